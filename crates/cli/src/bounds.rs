@@ -0,0 +1,188 @@
+use padded_number::PaddedNumber;
+
+use crate::*;
+
+/// Padded number with the widest length bounds, narrowed at runtime by
+/// [`Bounds`]
+pub type Number = PaddedNumber<0, { u8::MAX }>;
+
+/// Runtime length bounds
+///
+/// Counterpart to the const generic bounds of a `PaddedNumber`. Stepping is
+/// left to the `PaddedNumber` arithmetic, only steps which overflow the
+/// bounds are wrapped or saturated here.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds {
+    min: u8,
+    max: u8,
+    first: Number,
+    last: Number,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Overflow {
+    Error,
+    Wrap,
+    Saturate,
+}
+
+impl Bounds {
+    /// Upper bound of the `PaddedNumber` type, used unless `--max` is given
+    pub const DEFAULT_MAX: u8 = u8::MAX;
+    /// Lower bound of the `PaddedNumber` type, used unless `--min` is given
+    pub const DEFAULT_MIN: u8 = 1;
+
+    pub fn new(min: u8, max: u8) -> Result<Self, CliError> {
+        if min > max {
+            return Err(CliError::InvalidBounds(min, max));
+        }
+
+        Ok(Self {
+            min,
+            max,
+            first: Self::first_of_length(min),
+            last: Self::last_of_length(max),
+        })
+    }
+
+    pub fn check(&self, number: Number) -> Result<Number, CliError> {
+        match (self.first..=self.last).contains(&number) {
+            true => Ok(number),
+            false => Err(CliError::OutOfBounds(number.to_string(), self.min, self.max)),
+        }
+    }
+
+    pub fn add(&self, number: Number, rhs: u64, overflow: Overflow) -> Result<Number, CliError> {
+        let number = self.check(number)?;
+
+        if let Some(sum) = number.checked_add(rhs)
+            && sum <= self.last
+        {
+            return Ok(sum);
+        }
+
+        match overflow {
+            Overflow::Error => Err(CliError::Overflow(number.to_string())),
+            Overflow::Wrap => {
+                // steps left once the first number has been wrapped to
+                let remaining = (rhs as u128 - self.steps(number, self.last) - 1) % self.count();
+                Ok(self.first + remaining as u64)
+            }
+            Overflow::Saturate => Ok(self.last),
+        }
+    }
+
+    pub fn sub(&self, number: Number, rhs: u64, overflow: Overflow) -> Result<Number, CliError> {
+        let number = self.check(number)?;
+
+        if let Some(difference) = number.checked_sub(rhs)
+            && difference >= self.first
+        {
+            return Ok(difference);
+        }
+
+        match overflow {
+            Overflow::Error => Err(CliError::Overflow(number.to_string())),
+            Overflow::Wrap => {
+                // steps left once the last number has been wrapped to
+                let remaining = (rhs as u128 - self.steps(self.first, number) - 1) % self.count();
+                Ok(self.last - remaining as u64)
+            }
+            Overflow::Saturate => Ok(self.first),
+        }
+    }
+
+    /// Number of steps needed to move forward from `from` to `to`, wrapping
+    /// around if `to` comes before `from`
+    pub fn distance(&self, from: Number, to: Number) -> Result<u128, CliError> {
+        let from = self.check(from)?;
+        let to = self.check(to)?;
+
+        Ok(match from.steps_to(to) {
+            Some(steps) => steps,
+            None => self.count() - self.steps(to, from),
+        })
+    }
+
+    fn count(&self) -> u128 {
+        self.steps(self.first, self.last) + 1
+    }
+
+    fn steps(&self, from: Number, to: Number) -> u128 {
+        from.steps_to(to).expect("numbers in order")
+    }
+
+    fn first_of_length(length: u8) -> Number {
+        Number::try_new(&"0".repeat(length as usize)).expect("valid length")
+    }
+
+    fn last_of_length(length: u8) -> Number {
+        let length = length as usize;
+
+        // longer lengths are limited to the numbers an `u64` can store
+        let digits = match length > u64::MAX.ilog10() as usize {
+            true => format!("{:0>length$}", u64::MAX),
+            false => "9".repeat(length),
+        };
+
+        Number::try_new(&digits).expect("valid length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(str: &str) -> Number {
+        Number::try_new(str).unwrap()
+    }
+
+    #[test]
+    fn add() {
+        let bounds = Bounds::new(2, 3).unwrap();
+
+        assert_eq!(number("000"), bounds.add(number("99"), 1, Overflow::Error).unwrap());
+        assert!(bounds.add(number("999"), 1, Overflow::Error).is_err());
+        assert_eq!(number("00"), bounds.add(number("999"), 1, Overflow::Wrap).unwrap());
+        assert_eq!(number("999"), bounds.add(number("998"), 5, Overflow::Saturate).unwrap());
+    }
+
+    #[test]
+    fn sub() {
+        let bounds = Bounds::new(2, 3).unwrap();
+
+        assert_eq!(number("99"), bounds.sub(number("000"), 1, Overflow::Error).unwrap());
+        assert!(bounds.sub(number("00"), 1, Overflow::Error).is_err());
+        assert_eq!(number("999"), bounds.sub(number("00"), 1, Overflow::Wrap).unwrap());
+        assert_eq!(number("00"), bounds.sub(number("01"), 5, Overflow::Saturate).unwrap());
+    }
+
+    #[test]
+    fn follows_library_arithmetic() {
+        let bounds = Bounds::new(Bounds::DEFAULT_MIN, Bounds::DEFAULT_MAX).unwrap();
+        let last = Bounds::last_of_length(u8::MAX);
+
+        assert_eq!(number("00"), bounds.add(number("9"), 1, Overflow::Error).unwrap());
+        assert_eq!(number("0"), bounds.add(last, 1, Overflow::Wrap).unwrap());
+        assert_eq!(last, bounds.sub(number("0"), 1, Overflow::Wrap).unwrap());
+        assert!(
+            bounds
+                .check(bounds.add(last, u64::MAX, Overflow::Wrap).unwrap())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let bounds = Bounds::new(2, 2).unwrap();
+
+        assert_eq!(3, bounds.distance(number("07"), number("10")).unwrap());
+        assert_eq!(2, bounds.distance(number("99"), number("01")).unwrap());
+        assert!(bounds.distance(number("7"), number("10")).is_err());
+    }
+
+    #[test]
+    fn invalid_bounds() {
+        assert!(Bounds::new(3, 2).is_err());
+    }
+}
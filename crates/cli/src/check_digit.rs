@@ -0,0 +1,36 @@
+//! Luhn (mod 10) check digits
+
+/// Compute the check digit to append to the provided ASCII digits
+pub fn luhn(digits: &[u8]) -> u8 {
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            let digit = digit - b'0';
+            match index % 2 {
+                0 => match digit * 2 {
+                    doubled @ 10.. => doubled - 9,
+                    doubled => doubled,
+                },
+                _ => digit,
+            }
+        })
+        .fold(0_u64, |sum, digit| sum + digit as u64);
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luhn_check_digits() {
+        assert_eq!(3, luhn(b"7992739871"));
+        assert_eq!(0, luhn(b""));
+        assert_eq!(0, luhn(b"0000"));
+        // leading zeros do not affect the check digit
+        assert_eq!(luhn(b"18"), luhn(b"0018"));
+    }
+}
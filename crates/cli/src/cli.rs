@@ -0,0 +1,290 @@
+use std::{io::BufRead, str::FromStr};
+
+use clap::{Args, Parser, Subcommand};
+use padded_number::{PaddedNumber, ParsePaddedNumberError};
+use serde::Serialize;
+
+use crate::{bounds::Number, *};
+
+/// Work with numbers containing significant leading zeros
+#[derive(Parser)]
+#[command(version)]
+pub struct Cli {
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print a sequence of padded numbers, similar to `seq -w`
+    ///
+    /// Follows the crate's ordering, in which all numbers of one length come
+    /// before those of the next length. If LAST comes before FIRST, the
+    /// sequence wraps around from the end of the length bounds back to their
+    /// start, which is best combined with a narrower `--max`.
+    Seq {
+        first: Number,
+        last: Number,
+        /// Step between each number
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        step: u64,
+        #[command(flatten)]
+        bounds: BoundsArgs,
+    },
+    /// Increment padded numbers
+    Inc(StepArgs),
+    /// Decrement padded numbers
+    Dec(StepArgs),
+    /// Validate padded numbers, exiting unsuccessfully if any is invalid
+    Validate {
+        /// Minimum number of digits
+        #[arg(long, default_value_t = 0)]
+        min: u8,
+        /// Maximum number of digits
+        #[arg(long, default_value_t = u8::MAX)]
+        max: u8,
+        inputs: Vec<String>,
+    },
+    /// Sort padded numbers, shorter numbers before longer ones
+    Sort {
+        /// Sort in descending order
+        #[arg(long)]
+        reverse: bool,
+        /// Remove duplicates
+        #[arg(long)]
+        unique: bool,
+        inputs: Vec<String>,
+    },
+    /// Compute or verify Luhn check digits
+    #[command(subcommand)]
+    CheckDigit(CheckDigitCommand),
+}
+
+#[derive(Subcommand)]
+enum CheckDigitCommand {
+    /// Append the check digit to each input
+    Compute { inputs: Vec<String> },
+    /// Verify that the last digit of each input is its check digit, exiting
+    /// unsuccessfully if any is not
+    Verify { inputs: Vec<String> },
+}
+
+#[derive(Args)]
+struct StepArgs {
+    /// Amount to step by
+    #[arg(short, long, default_value_t = 1)]
+    amount: u64,
+    /// Wrap around the length bounds on overflow
+    #[arg(long, conflicts_with = "saturate")]
+    wrap: bool,
+    /// Stop at the length bounds on overflow
+    #[arg(long)]
+    saturate: bool,
+    #[command(flatten)]
+    bounds: BoundsArgs,
+    inputs: Vec<String>,
+}
+
+#[derive(Args)]
+struct BoundsArgs {
+    /// Minimum number of digits
+    #[arg(long, default_value_t = Bounds::DEFAULT_MIN)]
+    min: u8,
+    /// Maximum number of digits
+    #[arg(long, default_value_t = Bounds::DEFAULT_MAX)]
+    max: u8,
+}
+
+impl BoundsArgs {
+    fn bounds(&self) -> Result<Bounds, CliError> {
+        Bounds::new(self.min, self.max)
+    }
+}
+
+impl StepArgs {
+    fn overflow(&self) -> Overflow {
+        match (self.wrap, self.saturate) {
+            (true, _) => Overflow::Wrap,
+            (_, true) => Overflow::Saturate,
+            _ => Overflow::Error,
+        }
+    }
+
+    fn run(
+        self,
+        output: &mut Output,
+        step: fn(&Bounds, Number, u64, Overflow) -> Result<Number, CliError>,
+    ) -> Result<(), CliError> {
+        let overflow = self.overflow();
+        let bounds = self.bounds.bounds()?;
+
+        for input in read_inputs(self.inputs)? {
+            let number = parse::<Number>(input)?;
+            output.item(&step(&bounds, number, self.amount, overflow)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Cli {
+    /// Returns `false` if any input failed validation or verification
+    pub fn run(self) -> Result<bool, CliError> {
+        let mut output = Output::new(self.format);
+        let mut success = true;
+
+        match self.command {
+            Command::Seq { first, last, step, bounds } => {
+                let bounds = bounds.bounds()?;
+                let distance = bounds.distance(first, last)?;
+
+                let mut current = first;
+                let mut traversed = 0_u128;
+
+                loop {
+                    output.item(&current)?;
+
+                    traversed = match traversed.checked_add(step as u128) {
+                        Some(traversed) if traversed <= distance => traversed,
+                        _ => break,
+                    };
+
+                    current = bounds.add(current, step, Overflow::Wrap)?;
+                }
+            }
+            Command::Inc(step_args) => step_args.run(&mut output, Bounds::add)?,
+            Command::Dec(step_args) => step_args.run(&mut output, Bounds::sub)?,
+            Command::Validate { min, max, inputs } => {
+                for input in read_inputs(inputs)? {
+                    let validation = Validation::new(input, min, max);
+                    success &= validation.valid;
+                    output.item(&validation)?;
+                }
+            }
+            Command::Sort { reverse, unique, inputs } => {
+                let mut numbers = read_inputs(inputs)?
+                    .into_iter()
+                    .map(parse::<PaddedNumber<0, { u8::MAX }>>)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                numbers.sort();
+
+                if unique {
+                    numbers.dedup();
+                }
+
+                if reverse {
+                    numbers.reverse();
+                }
+
+                for number in &numbers {
+                    output.item(number)?;
+                }
+            }
+            Command::CheckDigit(CheckDigitCommand::Compute { inputs }) => {
+                for input in read_inputs(inputs)? {
+                    let number = parse::<PaddedNumber<1, { u8::MAX }>>(input)?.to_string();
+                    let check_digit = check_digit::luhn(number.as_bytes());
+                    output.item(&format!("{number}{check_digit}"))?;
+                }
+            }
+            Command::CheckDigit(CheckDigitCommand::Verify { inputs }) => {
+                for input in read_inputs(inputs)? {
+                    let verification = Verification::new(parse::<PaddedNumber<2, { u8::MAX }>>(input)?.to_string());
+                    success &= verification.valid;
+                    output.item(&verification)?;
+                }
+            }
+        }
+
+        output.finish()?;
+
+        Ok(success)
+    }
+}
+
+#[derive(Serialize)]
+struct Validation {
+    input: String,
+    valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Validation {
+    fn new(input: String, min: u8, max: u8) -> Self {
+        let result = input.parse::<PaddedNumber<0, { u8::MAX }>>().and_then(|number| {
+            let len = number.len();
+            match len {
+                _ if len < min => Err(ParsePaddedNumberError::TooShort(min, len)),
+                _ if len > max => Err(ParsePaddedNumberError::TooLong(max, len)),
+                _ => Ok(()),
+            }
+        });
+
+        Self {
+            input,
+            valid: result.is_ok(),
+            error: result.err().map(|err| err.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Validation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "{}: invalid, {error}", self.input),
+            None => write!(f, "{}: valid", self.input),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Verification {
+    input: String,
+    valid: bool,
+    expected_check_digit: u8,
+}
+
+impl Verification {
+    fn new(input: String) -> Self {
+        let (payload, check_digit) = input.split_at(input.len() - 1);
+        let expected_check_digit = check_digit::luhn(payload.as_bytes());
+        let valid = check_digit.as_bytes()[0] - b'0' == expected_check_digit;
+
+        Self { input, valid, expected_check_digit }
+    }
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.valid {
+            true => write!(f, "{}: valid", self.input),
+            false => write!(
+                f,
+                "{}: invalid, expected check digit '{}'",
+                self.input, self.expected_check_digit
+            ),
+        }
+    }
+}
+
+/// Use the provided arguments, or read lines from stdin if there are none
+fn read_inputs(inputs: Vec<String>) -> Result<Vec<String>, CliError> {
+    if !inputs.is_empty() {
+        return Ok(inputs);
+    }
+
+    std::io::stdin()
+        .lock()
+        .lines()
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+fn parse<T: FromStr<Err = ParsePaddedNumberError>>(input: String) -> Result<T, CliError> {
+    input.parse().map_err(|err| CliError::InvalidInput(input, err))
+}
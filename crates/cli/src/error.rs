@@ -0,0 +1,17 @@
+use padded_number::ParsePaddedNumberError;
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum CliError {
+    /// invalid input '{0}', {1}
+    InvalidInput(String, #[source] ParsePaddedNumberError),
+    /// invalid length bounds, expected '{0}' to be at most '{1}'
+    InvalidBounds(u8, u8),
+    /// '{0}' is not within the length bounds '{1}' to '{2}'
+    OutOfBounds(String, u8, u8),
+    /// '{0}' overflowed its length bounds, see --wrap and --saturate
+    Overflow(String),
+    /// failed to read input or write output
+    Io(#[from] std::io::Error),
+    /// failed to write JSON output
+    Json(#[from] serde_json::Error),
+}
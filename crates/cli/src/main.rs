@@ -0,0 +1,35 @@
+//! # `padded-number` - Command-line tool wrapping the `padded-number` crate
+//!
+//! Inputs are taken from the positional arguments, or from the lines of stdin
+//! if none were provided.
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+mod bounds;
+pub(crate) use bounds::{Bounds, Overflow};
+
+mod check_digit;
+
+mod cli;
+pub(crate) use cli::*;
+
+mod error;
+pub(crate) use error::CliError;
+
+mod output;
+pub(crate) use output::{Format, Output};
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
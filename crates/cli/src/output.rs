@@ -0,0 +1,69 @@
+use std::{
+    fmt::Display,
+    io::{BufWriter, StdoutLock, Write},
+};
+
+use serde::Serialize;
+
+use crate::*;
+
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub enum Format {
+    /// One value per line
+    #[default]
+    Plain,
+    /// A single JSON array
+    Json,
+}
+
+/// Streams items to stdout in the selected format
+///
+/// JSON arrays are written incrementally, so that long sequences need not be
+/// collected before being printed.
+pub struct Output {
+    format: Format,
+    writer: BufWriter<StdoutLock<'static>>,
+    item_count: usize,
+}
+
+impl Output {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            writer: BufWriter::new(std::io::stdout().lock()),
+            item_count: 0,
+        }
+    }
+
+    pub fn item<T: Display + Serialize>(&mut self, item: &T) -> Result<(), CliError> {
+        match self.format {
+            Format::Plain => writeln!(self.writer, "{item}")?,
+            Format::Json => {
+                let separator = match self.item_count {
+                    0 => "[",
+                    _ => ",",
+                };
+                self.writer.write_all(separator.as_bytes())?;
+                serde_json::to_writer(&mut self.writer, item)?;
+            }
+        }
+
+        self.item_count += 1;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<(), CliError> {
+        if let Format::Json = self.format {
+            let closing = match self.item_count {
+                0 => "[]\n",
+                _ => "]\n",
+            };
+            self.writer.write_all(closing.as_bytes())?;
+        }
+
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+#![allow(missing_docs)]
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Run the binary, returning whether it succeeded along with its stdout
+fn run(args: &[&str], stdin: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_padded-number"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn seq() {
+    assert_eq!((true, "8\n9\n00\n01\n".to_string()), run(&["seq", "8", "01"], ""));
+}
+
+#[test]
+fn seq_wraps() {
+    assert_eq!(
+        (true, "98\n99\n00\n01\n".to_string()),
+        run(&["seq", "98", "01", "--min", "2", "--max", "2"], "")
+    );
+}
+
+#[test]
+fn seq_json() {
+    assert_eq!(
+        (true, "[\"000\",\"002\",\"004\"]\n".to_string()),
+        run(&["seq", "000", "005", "--step", "2", "--format", "json"], "")
+    );
+}
+
+#[test]
+fn inc_from_stdin() {
+    assert_eq!((true, "0100\n10\n".to_string()), run(&["inc"], "0099\n09\n"));
+}
+
+#[test]
+fn inc_overflow() {
+    assert!(!run(&["inc", "--min", "2", "--max", "2", "99"], "").0);
+    assert_eq!(
+        (true, "00\n".to_string()),
+        run(&["inc", "--min", "2", "--max", "2", "--wrap", "99"], "")
+    );
+    assert_eq!(
+        (true, "99\n".to_string()),
+        run(&["inc", "--min", "2", "--max", "2", "--saturate", "99"], "")
+    );
+    assert_eq!(
+        (true, "0\n".to_string()),
+        run(&["inc", "--wrap", &format!("{:0>255}", u64::MAX)], "")
+    );
+}
+
+#[test]
+fn inc_library_bounds() {
+    assert_eq!((true, "00\n".to_string()), run(&["inc", "9"], ""));
+    assert_eq!((true, "000\n".to_string()), run(&["inc", "99"], ""));
+    assert!(!run(&["inc", "--min", "2", "9"], "").0);
+}
+
+#[test]
+fn dec() {
+    assert_eq!((true, "0095\n".to_string()), run(&["dec", "--amount", "5", "0100"], ""));
+    assert_eq!(
+        (true, "99\n".to_string()),
+        run(&["dec", "--min", "2", "--max", "2", "--wrap", "00"], "")
+    );
+}
+
+#[test]
+fn validate() {
+    let (success, stdout) = run(&["validate", "--min", "2", "--max", "3"], "012\n1\n");
+
+    assert!(!success);
+    assert!(stdout.starts_with("012: valid\n1: invalid"));
+    assert!(run(&["validate", "--min", "2", "--max", "3"], "012\n").0);
+}
+
+#[test]
+fn sort() {
+    assert_eq!(
+        (true, "9\n10\n001\n".to_string()),
+        run(&["sort", "--unique"], "001\n10\n9\n001\n")
+    );
+    assert_eq!(
+        (true, "001\n10\n9\n".to_string()),
+        run(&["sort", "--reverse"], "10\n001\n9\n")
+    );
+}
+
+#[test]
+fn invalid_input() {
+    assert_eq!((false, String::new()), run(&["sort"], "1a\n"));
+}
+
+#[test]
+fn check_digit() {
+    assert_eq!(
+        (true, "79927398713\n".to_string()),
+        run(&["check-digit", "compute", "7992739871"], "")
+    );
+    assert!(run(&["check-digit", "verify", "79927398713"], "").0);
+    assert!(!run(&["check-digit", "verify", "79927398710"], "").0);
+}
@@ -0,0 +1,113 @@
+//! Compares `PaddedNumberBitSet` against a `BTreeSet` of padded numbers
+//!
+//! Run with `cargo bench --bench bit_set`.
+
+#![allow(missing_docs)]
+
+use std::{collections::BTreeSet, hint::black_box};
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use padded_number::{CompactPaddedNumber, PaddedNumber, PaddedNumberBitSet};
+
+type Number = PaddedNumber<1, 6>;
+
+const MEMBER_COUNTS: [u64; 2] = [100_000, 500_000];
+
+/// Members spread across the length bounds, in no particular order
+fn members(count: u64) -> Vec<Number> {
+    let capacity = PaddedNumberBitSet::<1, 6>::CAPACITY;
+
+    (0..count)
+        .map(|index| index * 7919 % capacity)
+        .map(|offset| {
+            CompactPaddedNumber::<1, 6>::from_ordinal(offset + 1)
+                .expect("ordinal within bounds")
+                .into_padded_number()
+        })
+        .collect()
+}
+
+fn insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+
+    for count in MEMBER_COUNTS {
+        let members = members(count);
+
+        group.bench_with_input(BenchmarkId::new("PaddedNumberBitSet", count), &members, |b, members| {
+            b.iter(|| members.iter().copied().collect::<PaddedNumberBitSet<1, 6>>())
+        });
+        group.bench_with_input(BenchmarkId::new("BTreeSet", count), &members, |b, members| {
+            b.iter(|| members.iter().copied().collect::<BTreeSet<_>>())
+        });
+    }
+
+    group.finish();
+}
+
+fn contains(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contains");
+
+    for count in MEMBER_COUNTS {
+        let members = members(count);
+        let bit_set = members.iter().copied().collect::<PaddedNumberBitSet<1, 6>>();
+        let btree_set = members.iter().copied().collect::<BTreeSet<_>>();
+
+        group.bench_with_input(BenchmarkId::new("PaddedNumberBitSet", count), &members, |b, members| {
+            b.iter(|| members.iter().filter(|number| bit_set.contains(**number)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("BTreeSet", count), &members, |b, members| {
+            b.iter(|| members.iter().filter(|number| btree_set.contains(number)).count())
+        });
+    }
+
+    group.finish();
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+
+    for count in MEMBER_COUNTS {
+        let members = members(count);
+        let bit_set = members.iter().copied().collect::<PaddedNumberBitSet<1, 6>>();
+        let btree_set = members.iter().copied().collect::<BTreeSet<_>>();
+
+        group.bench_function(BenchmarkId::new("PaddedNumberBitSet", count), |b| {
+            b.iter(|| black_box(&bit_set).iter().count())
+        });
+        group.bench_function(BenchmarkId::new("BTreeSet", count), |b| {
+            b.iter(|| black_box(&btree_set).iter().count())
+        });
+    }
+
+    group.finish();
+}
+
+fn union(c: &mut Criterion) {
+    let mut group = c.benchmark_group("union");
+
+    for count in MEMBER_COUNTS {
+        let members = members(count);
+        let (lhs, rhs) = members.split_at(members.len() / 2);
+
+        let bit_sets = (
+            lhs.iter().copied().collect::<PaddedNumberBitSet<1, 6>>(),
+            rhs.iter().copied().collect::<PaddedNumberBitSet<1, 6>>(),
+        );
+        let btree_sets = (
+            lhs.iter().copied().collect::<BTreeSet<_>>(),
+            rhs.iter().copied().collect::<BTreeSet<_>>(),
+        );
+
+        group.bench_function(BenchmarkId::new("PaddedNumberBitSet", count), |b| {
+            b.iter(|| bit_sets.0.union(&bit_sets.1))
+        });
+        group.bench_function(BenchmarkId::new("BTreeSet", count), |b| {
+            b.iter(|| btree_sets.0.union(&btree_sets.1).copied().collect::<BTreeSet<_>>())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, insert, contains, iterate, union);
+criterion_main!(benches);
@@ -0,0 +1,259 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::*;
+
+/// Irregularity found by a [`SeriesAnalysis`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeriesFinding<const A: u8, const B: u8> {
+    /// Numbers skipped between the highest number so far and the current one
+    Missing(RangeInclusive<PaddedNumber<A, B>>),
+    /// Number which has already been encountered, `count` being the number of
+    /// occurrences so far
+    Duplicate {
+        /// The duplicated number
+        number: PaddedNumber<A, B>,
+        /// Occurrences so far, including this one
+        count: u64,
+    },
+    /// Number lower than one encountered before it
+    OutOfOrder {
+        /// The out of order number
+        number: PaddedNumber<A, B>,
+        /// The highest number so far
+        after: PaddedNumber<A, B>,
+    },
+}
+
+/// Streaming analysis of a series of padded numbers, e.g. invoice numbers
+///
+/// Iterating over the analysis consumes the inner iterator, yielding a
+/// [`SeriesFinding`] for each number which is not the direct successor of the
+/// highest number so far. Successors follow the crate's ordering, so `"0999"`
+/// is followed by `"1000"` and `"9"` by `"00"`. Each number results in at most
+/// one finding: duplicates take precedence over being out of order.
+///
+/// Encountered numbers are kept track of as ranges, so memory usage grows
+/// with the number of gaps, not with the number of inputs.
+///
+/// ```rust
+/// # use padded_number::*;
+/// let numbers = ["8", "9", "00", "02", "02", "01"]
+///     .iter()
+///     .map(|str| PaddedNumber::<1, 2>::try_new(str).unwrap());
+///
+/// let mut analysis = SeriesAnalysis::new(numbers);
+///
+/// let findings = analysis.by_ref().collect::<Vec<_>>();
+/// assert_eq!(3, findings.len());
+/// assert!(matches!(findings[0], SeriesFinding::Missing(_)));
+/// assert!(matches!(
+///     findings[1],
+///     SeriesFinding::Duplicate { count: 2, .. }
+/// ));
+/// assert!(matches!(findings[2], SeriesFinding::OutOfOrder { .. }));
+///
+/// // "01" arrived late, but it did arrive
+/// let start = bound_padded_number!(1, 2, "8");
+/// let end = bound_padded_number!(1, 2, "02");
+/// assert!(analysis.is_complete(start..=end));
+/// ```
+///
+/// Like with [`CompactPaddedNumber`], the upper length bound may not exceed
+/// 19.
+#[derive(Debug, Clone)]
+pub struct SeriesAnalysis<I, const A: u8 = 1, const B: u8 = 19> {
+    numbers: I,
+    seen: PaddedRangeSet<A, B>,
+    duplicates: BTreeMap<CompactPaddedNumber<A, B>, u64>,
+    highest: Option<CompactPaddedNumber<A, B>>,
+}
+
+impl<I: Iterator<Item = PaddedNumber<A, B>>, const A: u8, const B: u8> SeriesAnalysis<I, A, B> {
+    /// Analyze a series of numbers
+    pub fn new(numbers: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            numbers: numbers.into_iter(),
+            seen: PaddedRangeSet::new(),
+            duplicates: BTreeMap::new(),
+            highest: None,
+        }
+    }
+
+    /// Consume the remaining numbers, discarding any findings
+    pub fn finish(mut self) -> Self {
+        self.by_ref().for_each(drop);
+        self
+    }
+
+    /// Numbers encountered so far
+    pub fn seen(&self) -> &PaddedRangeSet<A, B> {
+        &self.seen
+    }
+
+    /// Numbers encountered more than once so far, along with their number of
+    /// occurrences
+    pub fn duplicates(&self) -> impl Iterator<Item = (PaddedNumber<A, B>, u64)> + '_ {
+        self.duplicates
+            .iter()
+            .map(|(number, count)| (number.into_padded_number(), *count))
+    }
+
+    /// Numbers not yet encountered between the lowest and highest numbers
+    /// encountered so far
+    pub fn missing(&self) -> PaddedRangeSet<A, B> {
+        let mut ranges = self.seen.ranges();
+
+        match (ranges.next(), ranges.next_back()) {
+            (Some(first), Some(last)) => self.missing_within(*first.start()..=*last.end()),
+            _ => PaddedRangeSet::new(),
+        }
+    }
+
+    /// Numbers not yet encountered within a series
+    pub fn missing_within(&self, series: RangeInclusive<PaddedNumber<A, B>>) -> PaddedRangeSet<A, B> {
+        let mut missing = PaddedRangeSet::new();
+        missing.insert(series);
+        missing.difference(&self.seen)
+    }
+
+    /// Check if every number of a series has been encountered so far
+    pub fn is_complete(&self, series: RangeInclusive<PaddedNumber<A, B>>) -> bool {
+        self.missing_within(series).is_empty()
+    }
+
+    fn analyze(&mut self, number: CompactPaddedNumber<A, B>) -> Option<SeriesFinding<A, B>> {
+        let padded_number = number.into_padded_number();
+
+        if self.seen.contains(padded_number) {
+            let count = self.duplicates.entry(number).or_insert(1);
+            *count += 1;
+
+            return Some(SeriesFinding::Duplicate { number: padded_number, count: *count });
+        }
+
+        self.seen.insert(padded_number..=padded_number);
+
+        let Some(highest) = self.highest else {
+            self.highest = Some(number);
+            return None;
+        };
+
+        if number < highest {
+            return Some(SeriesFinding::OutOfOrder { number: padded_number, after: highest.into_padded_number() });
+        }
+
+        self.highest = Some(number);
+
+        // number > highest, both being within bounds
+        let first_missing = highest.wrapping_add(1);
+
+        match first_missing == number {
+            true => None,
+            false => Some(SeriesFinding::Missing(
+                first_missing.into_padded_number()..=number.wrapping_sub(1).into_padded_number(),
+            )),
+        }
+    }
+}
+
+impl<I: Iterator<Item = PaddedNumber<A, B>>, const A: u8, const B: u8> Iterator for SeriesAnalysis<I, A, B> {
+    type Item = SeriesFinding<A, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let number = CompactPaddedNumber::from_padded_number(self.numbers.next()?);
+
+            if let Some(finding) = self.analyze(number) {
+                return Some(finding);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    fn analysis(numbers: &[&str]) -> SeriesAnalysis<impl Iterator<Item = PaddedNumber<1, 4>>, 1, 4> {
+        SeriesAnalysis::new(numbers.iter().map(|str| mock_from_str::<1, 4>(str)).collect::<Vec<_>>())
+    }
+
+    fn range(start: &str, end: &str) -> RangeInclusive<PaddedNumber<1, 4>> {
+        mock_from_str(start)..=mock_from_str(end)
+    }
+
+    #[test]
+    fn consecutive_across_lengths() {
+        assert_eq!(0, analysis(&["8", "9", "00", "01"]).count());
+        assert_eq!(0, analysis(&["0998", "0999", "1000"]).count());
+    }
+
+    #[test]
+    fn missing() {
+        let findings = analysis(&["8", "01", "02", "05"]).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                SeriesFinding::Missing(range("9", "00")),
+                SeriesFinding::Missing(range("03", "04"))
+            ],
+            findings
+        );
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut analysis = analysis(&["1", "1", "2", "1", "2"]);
+
+        assert_eq!(
+            vec![
+                SeriesFinding::Duplicate { number: mock_from_str("1"), count: 2 },
+                SeriesFinding::Duplicate { number: mock_from_str("1"), count: 3 },
+                SeriesFinding::Duplicate { number: mock_from_str("2"), count: 2 },
+            ],
+            analysis.by_ref().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![(mock_from_str("1"), 3), (mock_from_str("2"), 2)],
+            analysis.duplicates().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn out_of_order() {
+        let findings = analysis(&["1", "3", "2"]).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                SeriesFinding::Missing(range("2", "2")),
+                SeriesFinding::OutOfOrder { number: mock_from_str("2"), after: mock_from_str("3") }
+            ],
+            findings
+        );
+    }
+
+    #[test]
+    fn completeness() {
+        let analysis = analysis(&["8", "01", "9", "00", "03"]).finish();
+
+        assert!(analysis.is_complete(range("8", "01")));
+        assert!(!analysis.is_complete(range("7", "01")));
+        assert!(!analysis.is_complete(range("8", "03")));
+
+        assert_eq!(vec![range("02", "02")], analysis.missing().ranges().collect::<Vec<_>>());
+        assert_eq!(
+            vec![range("7", "7"), range("02", "02"), range("04", "05")],
+            analysis.missing_within(range("7", "05")).ranges().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let analysis = analysis(&[]).finish();
+
+        assert!(analysis.missing().is_empty());
+        assert!(!analysis.is_complete(range("1", "1")));
+    }
+}
@@ -1,19 +1,32 @@
-use std::ops::{Add, Sub};
+use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use crate::*;
 
 impl<const A: u8, const B: u8> PaddedNumber<A, B> {
+    /// Count of the padded numbers within the length bounds
+    pub(crate) const COUNT: u128 = Self::bounds_count(ValueRange::FULL);
+    /// Largest padded number within the length bounds
+    pub(crate) const LAST: Self = Self::from_offset(Self::COUNT - 1, ValueRange::FULL);
+
     /// Wrapping addition with u64 as right-hand side
     ///
     /// Used within the `impl Add<u64> for PaddedNumber` implementation.
     ///
     /// ```rust
     /// # use padded_number_macros::*;
-    /// assert_eq!(padded_number!("0") + 1, padded_number!("0").wrapping_add(1));
+    /// # use padded_number::PaddedNumber;
+    /// let number: PaddedNumber = padded_number!("0");
+    /// assert_eq!(number + 1, number.wrapping_add(1));
     ///
     /// // Within bounds
-    /// assert_eq!(padded_number!("9") + 1, padded_number!("00"));
-    /// assert_eq!(padded_number!("80") + 11, padded_number!("91"));
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "9") + 1,
+    ///     bound_padded_number!(1, 3, "00")
+    /// );
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "80") + 11,
+    ///     bound_padded_number!(1, 3, "91")
+    /// );
     ///
     /// // Wrapped
     /// assert_eq!(
@@ -21,16 +34,9 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
     ///     bound_padded_number!(2, 3, "01")
     /// );
     /// ```
-    pub fn wrapping_add(self, rhs: u64) -> Self {
-        self.add_impl(rhs, |new_number, max_for_length_number| {
-            let start = Self::min_number_for_min_length();
-
-            match B == 0 {
-                true => start,
-                // -1 because '0' counts as a step
-                false => start.wrapping_add(new_number - max_for_length_number - 1),
-            }
-        })
+    pub const fn wrapping_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Wrap, ValueRange::FULL, Self::COUNT)
+            .expect("wrapping never fails")
     }
 
     /// Saturating addition with u64 as right-hand side
@@ -44,11 +50,25 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
     /// ```
     ///
     /// Addition within bounds behaves the same as in [`Self::wrapping_add`].
-    pub fn saturating_add(self, rhs: u64) -> Self {
-        self.add_impl(rhs, |_new_number, max_for_length_number| Self {
-            leading_zeros: 0,
-            number: max_for_length_number,
-        })
+    pub const fn saturating_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Saturate, ValueRange::FULL, Self::COUNT)
+            .expect("saturating never fails")
+    }
+
+    /// Checked addition with u64 as right-hand side
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(bound_padded_number!(2, 3, "000")),
+    ///     bound_padded_number!(2, 3, "99").checked_add(1)
+    /// );
+    /// assert_eq!(None, bound_padded_number!(2, 3, "990").checked_add(10));
+    /// ```
+    ///
+    /// Addition within bounds behaves the same as in [`Self::wrapping_add`].
+    pub const fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.add_impl(rhs, OverflowStrategy::Fail, ValueRange::FULL, Self::COUNT)
     }
 
     /// Wrapping subtraction with u64 as right-hand side
@@ -57,22 +77,29 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
     ///
     /// ```rust
     /// # use padded_number_macros::*;
-    /// assert_eq!(padded_number!("9") - 1, padded_number!("9").wrapping_sub(1));
+    /// # use padded_number::PaddedNumber;
+    /// let number: PaddedNumber = padded_number!("9");
+    /// assert_eq!(number - 1, number.wrapping_sub(1));
     ///
     /// // Within bounds
-    /// assert_eq!(padded_number!("9") + 1, padded_number!("00"));
-    /// assert_eq!(padded_number!("80") + 11, padded_number!("91"));
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "00") - 1,
+    ///     bound_padded_number!(1, 3, "9")
+    /// );
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "91") - 11,
+    ///     bound_padded_number!(1, 3, "80")
+    /// );
     ///
     /// // Wrapped
     /// assert_eq!(
-    ///     bound_padded_number!(2, 3, "999") + 2,
-    ///     bound_padded_number!(2, 3, "01")
+    ///     bound_padded_number!(2, 3, "01") - 2,
+    ///     bound_padded_number!(2, 3, "999")
     /// );
     /// ```
-    pub fn wrapping_sub(self, rhs: u64) -> Self {
-        self.sub_impl(rhs, |remaining_difference| {
-            Self::max_number_for_max_length().wrapping_sub(remaining_difference)
-        })
+    pub const fn wrapping_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Wrap, ValueRange::FULL, Self::COUNT)
+            .expect("wrapping never fails")
     }
 
     /// Saturating subtraction with u64 as right-hand side
@@ -86,101 +113,339 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
     /// ```
     ///
     /// Subtraction within bounds behaves the same as in [`Self::wrapping_sub`].
-    pub fn saturating_sub(self, rhs: u64) -> Self {
-        self.sub_impl(rhs, |_| Self::min_number_for_min_length())
+    pub const fn saturating_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Saturate, ValueRange::FULL, Self::COUNT)
+            .expect("saturating never fails")
     }
 
-    // can't yet be const due to Rust currently missing const closures
-    fn add_impl(self, rhs: u64, overflow_fn: fn(u64, u64) -> Self) -> Self {
-        if rhs == 0 {
-            return self;
+    /// Checked subtraction with u64 as right-hand side
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(bound_padded_number!(2, 3, "99")),
+    ///     bound_padded_number!(2, 3, "000").checked_sub(1)
+    /// );
+    /// assert_eq!(None, bound_padded_number!(2, 3, "05").checked_sub(6));
+    /// ```
+    ///
+    /// Subtraction within bounds behaves the same as in [`Self::wrapping_sub`].
+    pub const fn checked_sub(self, rhs: u64) -> Option<Self> {
+        self.sub_impl(rhs, OverflowStrategy::Fail, ValueRange::FULL, Self::COUNT)
+    }
+
+    /// Number of additions of one needed to get from `self` to `end`
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(3),
+    ///     bound_padded_number!(1, 3, "98").steps_to(bound_padded_number!(1, 3, "001"))
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     bound_padded_number!(1, 3, "001").steps_to(bound_padded_number!(1, 3, "98"))
+    /// );
+    /// ```
+    ///
+    /// Returns `None` if `end` comes before `self`, as reaching it would
+    /// require wrapping around the length bounds.
+    pub const fn steps_to(self, end: Self) -> Option<u128> {
+        end.offset(ValueRange::FULL).checked_sub(self.offset(ValueRange::FULL))
+    }
+
+    /// Returns `None` only on overflow with [`OverflowStrategy::Fail`]
+    ///
+    /// `count` is expected to be the [`Self::bounds_count`] of the range.
+    pub(crate) const fn add_impl(
+        self,
+        rhs: u64,
+        strategy: OverflowStrategy,
+        range: ValueRange,
+        count: u128,
+    ) -> Option<Self> {
+        // no overflow, preserve length
+        if let Some(new_number) = self.number.checked_add(rhs)
+            && (new_number as u128) < length_count(self.len())
+            && new_number <= range.high
+        {
+            return Some(self.with_value(new_number));
         }
 
-        let new_number = self.number + rhs;
+        match ordinal::add_offset(self.offset(range), rhs, count, strategy) {
+            Some(offset) => Some(Self::from_offset(offset, range)),
+            None => None,
+        }
+    }
 
-        let max_for_length = self.max_number_for_current_length();
-        let max_for_length_number = max_for_length.number;
+    /// Returns `None` only on overflow with [`OverflowStrategy::Fail`]
+    ///
+    /// `count` is expected to be the [`Self::bounds_count`] of the range.
+    pub(crate) const fn sub_impl(
+        self,
+        rhs: u64,
+        strategy: OverflowStrategy,
+        range: ValueRange,
+        count: u128,
+    ) -> Option<Self> {
+        // nothing to subtract from
+        if self.is_empty() {
+            return Some(self);
+        }
 
-        // check for overflow
-        if new_number > max_for_length_number {
-            // handle overflow
-            if self.len() == B {
-                overflow_fn(new_number, max_for_length_number)
-            }
-            // recursively add one leading zero
-            else {
-                let next_number = Self { leading_zeros: self.len() + 1, number: 0 };
+        // no overflow, preserve length
+        if rhs <= self.number - range.low {
+            return Some(self.with_value(self.number - rhs));
+        }
 
-                let diff_to_next_increase = max_for_length_number - self.number;
-                let rhs_next = rhs - diff_to_next_increase - 1;
+        match ordinal::sub_offset(self.offset(range), rhs, count, strategy) {
+            Some(offset) => Some(Self::from_offset(offset, range)),
+            None => None,
+        }
+    }
+
+    /// Position of the padded number among all padded numbers within the
+    /// length bounds and value range, following their ordering
+    ///
+    /// The padded number is expected to be within the value range.
+    pub(crate) const fn offset(&self, range: ValueRange) -> u128 {
+        (self.number - range.low) as u128 + range.lengths_count(A, self.len())
+    }
+
+    /// Inverse of [`Self::offset`]
+    ///
+    /// # Panics
+    /// - If offset >= [`Self::bounds_count`]
+    pub(crate) const fn from_offset(mut offset: u128, range: ValueRange) -> Self {
+        let mut length = A;
 
-                next_number.add_impl(rhs_next, overflow_fn)
+        loop {
+            let count = range.length_count(length);
+
+            if offset < count {
+                let number = range.low + offset as u64;
+                return Self { leading_zeros: length - utils::number_len(number), number };
             }
-        }
-        // no overflow, preserve number of digits
-        else {
-            let new_number_digits = digit_length(new_number);
-            let current_digits = digit_length(self.number);
-            let leading_zeroes_to_remove = new_number_digits - current_digits;
-
-            Self {
-                leading_zeros: self.leading_zeros - leading_zeroes_to_remove,
-                number: new_number,
+
+            // skip past lengths sharing the same count all at once
+            if length >= range.saturated_length() && count > 0 {
+                length += (offset / count) as u8;
+                offset %= count;
+            } else {
+                offset -= count;
+                length += 1;
             }
         }
     }
 
-    fn sub_impl(self, rhs: u64, overflow_fn: impl FnOnce(u64) -> Self) -> Self {
-        if rhs == 0 || self.is_empty() {
-            return self;
+    /// Count the padded numbers within the length bounds and value range
+    pub(crate) const fn bounds_count(range: ValueRange) -> u128 {
+        match A <= B {
+            true => range.lengths_count(A, B) + range.length_count(B),
+            false => 0,
         }
+    }
+}
 
-        match self.number.checked_sub(rhs) {
-            // within does not overflow, preserve length
-            Some(new_number) => {
-                let current_digits = digit_length(self.number);
-                let new_number_digits = digit_length(new_number);
-                let leading_zeroes_to_add = current_digits - new_number_digits;
-
-                Self {
-                    leading_zeros: self.leading_zeros + leading_zeroes_to_add,
-                    number: new_number,
-                }
-            }
-            // within overflows
-            None => {
-                let length = self.len();
-
-                if length == A {
-                    // -1 because '0' counts as a step
-                    overflow_fn(rhs - self.number - 1)
-                } else {
-                    let next_number =
-                        PaddedNumber { leading_zeros: 0, number: Self::max_number_for_length_impl(length - 1) };
-
-                    let overflow_diff = rhs - self.number;
-                    let next_rhs = overflow_diff - 1;
-
-                    next_number.sub_impl(next_rhs, overflow_fn)
-                }
-            }
+/// Multiplication, division and remainder with u64 as right-hand side
+///
+/// Unlike addition and subtraction, which step through the ordering of padded
+/// numbers and may therefore change their length, these operate on the numeric
+/// value while preserving the length of the padded number. `"01234" / 100 *
+/// 100` is `"01200"`, and `"01234" % 7` is `"00002"`. Results are always
+/// within the length bounds as a consequence.
+///
+/// Overflow instead happens once the value no longer fits within the length,
+/// upon which wrapping is done modulo 10^length, e.g. `"75" * 2` wraps to
+/// `"50"`. Saturation is done to the largest value of the length, all nines.
+/// Lengths above 19 are limited by the `u64` which stores the remaining
+/// number, and wrap modulo 2^64 instead.
+///
+/// The ordering of padded numbers is thereby only followed by addition and
+/// subtraction. Repeated addition is not multiplication: `"5" + 5` steps past
+/// `"9"` onto `"00"`, whereas `"5" * 2` wraps around to `"0"`. Results of
+/// these may also be ordered before their input, as with `"75" * 2`.
+///
+/// ```rust
+/// # use padded_number_macros::*;
+/// let number = bound_padded_number!(1, 2, "5");
+///
+/// assert_eq!(bound_padded_number!(1, 2, "00"), number + 5);
+/// assert_eq!(bound_padded_number!(1, 2, "0"), number * 2);
+/// ```
+impl<const A: u8, const B: u8> PaddedNumber<A, B> {
+    /// Wrapping multiplication with u64 as right-hand side
+    ///
+    /// Used within the `impl Mul<u64> for PaddedNumber` implementation.
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "012") * 3,
+    ///     bound_padded_number!(1, 3, "036")
+    /// );
+    ///
+    /// // Wrapped
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "75") * 2,
+    ///     bound_padded_number!(1, 3, "50")
+    /// );
+    /// ```
+    pub const fn wrapping_mul(self, rhs: u64) -> Self {
+        self.mul_impl(rhs, OverflowStrategy::Wrap)
+            .expect("wrapping never fails")
+    }
+
+    /// Saturating multiplication with u64 as right-hand side
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 3, "075").saturating_mul(20),
+    ///     bound_padded_number!(1, 3, "999") // saturated
+    /// );
+    /// ```
+    ///
+    /// Multiplication within the length behaves the same as in
+    /// [`Self::wrapping_mul`].
+    pub const fn saturating_mul(self, rhs: u64) -> Self {
+        self.mul_impl(rhs, OverflowStrategy::Saturate)
+            .expect("saturating never fails")
+    }
+
+    /// Checked multiplication with u64 as right-hand side
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(bound_padded_number!(1, 3, "099")),
+    ///     bound_padded_number!(1, 3, "033").checked_mul(3)
+    /// );
+    /// assert_eq!(None, bound_padded_number!(1, 3, "50").checked_mul(2));
+    /// ```
+    ///
+    /// Multiplication within the length behaves the same as in
+    /// [`Self::wrapping_mul`].
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.mul_impl(rhs, OverflowStrategy::Fail)
+    }
+
+    /// Wrapping division with u64 as right-hand side
+    ///
+    /// Used within the `impl Div<u64> for PaddedNumber` implementation.
+    /// Division can't overflow, making the wrapping, saturating and checked
+    /// variants differ only in their handling of division by zero.
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 5, "01234") / 100,
+    ///     bound_padded_number!(1, 5, "00012")
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// - If `rhs` is zero
+    pub const fn wrapping_div(self, rhs: u64) -> Self {
+        self.with_value(self.number / rhs)
+    }
+
+    /// Saturating division with u64 as right-hand side, same as
+    /// [`Self::wrapping_div`]
+    ///
+    /// # Panics
+    /// - If `rhs` is zero
+    pub const fn saturating_div(self, rhs: u64) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Checked division with u64 as right-hand side, returning `None` if `rhs`
+    /// is zero
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(bound_padded_number!(1, 5, "00012")),
+    ///     bound_padded_number!(1, 5, "01234").checked_div(100)
+    /// );
+    /// assert_eq!(None, bound_padded_number!(1, 5, "01234").checked_div(0));
+    /// ```
+    pub const fn checked_div(self, rhs: u64) -> Option<Self> {
+        match self.number.checked_div(rhs) {
+            Some(value) => Some(self.with_value(value)),
+            None => None,
         }
     }
 
-    const fn max_number_for_current_length(&self) -> PaddedNumber<A, B> {
-        PaddedNumber { leading_zeros: 0, number: Self::max_number_for_length_impl(self.len()) }
+    /// Wrapping remainder with u64 as right-hand side
+    ///
+    /// Used within the `impl Rem<u64> for PaddedNumber` implementation. Like
+    /// with division, the variants only differ in their handling of a zero
+    /// `rhs`.
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     bound_padded_number!(1, 5, "01234") % 7,
+    ///     bound_padded_number!(1, 5, "00002")
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// - If `rhs` is zero
+    pub const fn wrapping_rem(self, rhs: u64) -> Self {
+        self.with_value(self.number % rhs)
     }
 
-    const fn max_number_for_max_length() -> PaddedNumber<A, B> {
-        PaddedNumber { leading_zeros: 0, number: Self::max_number_for_length_impl(B) }
+    /// Saturating remainder with u64 as right-hand side, same as
+    /// [`Self::wrapping_rem`]
+    ///
+    /// # Panics
+    /// - If `rhs` is zero
+    pub const fn saturating_rem(self, rhs: u64) -> Self {
+        self.wrapping_rem(rhs)
     }
 
-    const fn min_number_for_min_length() -> Self {
-        Self { leading_zeros: A, number: 0 }
+    /// Checked remainder with u64 as right-hand side, returning `None` if
+    /// `rhs` is zero
+    ///
+    /// ```rust
+    /// # use padded_number_macros::*;
+    /// assert_eq!(
+    ///     Some(bound_padded_number!(1, 5, "00002")),
+    ///     bound_padded_number!(1, 5, "01234").checked_rem(7)
+    /// );
+    /// assert_eq!(None, bound_padded_number!(1, 5, "01234").checked_rem(0));
+    /// ```
+    pub const fn checked_rem(self, rhs: u64) -> Option<Self> {
+        match self.number.checked_rem(rhs) {
+            Some(value) => Some(self.with_value(value)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` only on overflow with [`OverflowStrategy::Fail`]
+    const fn mul_impl(self, rhs: u64, strategy: OverflowStrategy) -> Option<Self> {
+        let count = length_count(self.len());
+        let product = self.number as u128 * rhs as u128;
+
+        if product < count {
+            return Some(self.with_value(product as u64));
+        }
+
+        match strategy {
+            OverflowStrategy::Wrap => Some(self.with_value((product % count) as u64)),
+            OverflowStrategy::Saturate => Some(self.with_value((count - 1) as u64)),
+            OverflowStrategy::Fail => None,
+        }
     }
 
-    const fn max_number_for_length_impl(length: u8) -> u64 {
-        (10_u64.pow(length as u32)) - 1
+    /// Replace the numeric value while preserving the length
+    ///
+    /// # Panics
+    /// - If the value has more digits than the current length
+    const fn with_value(self, value: u64) -> Self {
+        Self { leading_zeros: self.len() - utils::number_len(value), number: value }
     }
 }
 
@@ -200,52 +465,154 @@ impl<const A: u8, const B: u8> Sub<u64> for PaddedNumber<A, B> {
     }
 }
 
-const fn digit_length(n: u64) -> u8 {
-    if n == 0 {
-        0
-    } else {
-        let mut count = 1;
-        let mut current = n;
+impl<const A: u8, const B: u8> Mul<u64> for PaddedNumber<A, B> {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self::wrapping_mul(self, rhs)
+    }
+}
 
-        while current >= 10 {
-            count += 1;
-            current /= 10
+impl<const A: u8, const B: u8> Div<u64> for PaddedNumber<A, B> {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        Self::wrapping_div(self, rhs)
+    }
+}
+
+impl<const A: u8, const B: u8> Rem<u64> for PaddedNumber<A, B> {
+    type Output = Self;
+
+    fn rem(self, rhs: u64) -> Self::Output {
+        Self::wrapping_rem(self, rhs)
+    }
+}
+
+/// What to do once a padded number would step past its length bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowStrategy {
+    /// Continue from the other end of the bounds, as in
+    /// [`PaddedNumber::wrapping_add`]
+    Wrap,
+    /// Stay at the bound that was reached, as in
+    /// [`PaddedNumber::saturating_add`]
+    Saturate,
+    /// Fail with an error
+    Fail,
+}
+
+/// Numeric values which padded numbers may take on, regardless of their length
+#[derive(Clone, Copy)]
+pub(crate) struct ValueRange {
+    pub(crate) low: u64,
+    pub(crate) high: u64,
+}
+
+impl ValueRange {
+    pub(crate) const FULL: Self = Self { low: 0, high: u64::MAX };
+
+    pub(crate) const fn contains(self, number: u64) -> bool {
+        self.low <= number && number <= self.high
+    }
+
+    /// Count the padded numbers with a length within `min..max` within the
+    /// range
+    ///
+    /// Visits at most the lengths up to [`Self::saturated_length`], as all
+    /// lengths from there on share the same count.
+    const fn lengths_count(self, min: u8, max: u8) -> u128 {
+        let mut count = 0;
+
+        let mut length = min;
+        while length < max && length < self.saturated_length() {
+            count += self.length_count(length);
+            length += 1;
+        }
+
+        if length < max {
+            count += (max - length) as u128 * self.length_count(length);
         }
 
         count
     }
+
+    /// Shortest length which is able to hold all numbers within the range,
+    /// or every number an `u64` can represent
+    const fn saturated_length(self) -> u8 {
+        utils::number_len(self.high)
+    }
+
+    /// Count the padded numbers of a given length within the range
+    const fn length_count(self, length: u8) -> u128 {
+        let max_for_length = length_count(length) - 1;
+
+        match (self.low as u128) <= max_for_length && self.low <= self.high {
+            true => {
+                let high = match (self.high as u128) < max_for_length {
+                    true => self.high as u128,
+                    false => max_for_length,
+                };
+                high - self.low as u128 + 1
+            }
+            false => 0,
+        }
+    }
+}
+
+/// Count the padded numbers of a given length
+///
+/// Remaining numbers are stored as an `u64`, so lengths above 19 can't
+/// represent every value. These are skipped, making for example `"0"` the
+/// successor of `"18446744073709551615"` if the length bounds are `1..=20`.
+const fn length_count(length: u8) -> u128 {
+    match 10_u128.checked_pow(length as u32) {
+        Some(count) if count <= u64::MAX as u128 => count,
+        _ => u64::MAX as u128 + 1,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::ValueRange;
     use crate::{tests::mock_from_str, *};
 
     #[test]
-    fn max_number_for_current_length() {
-        assert_impl("", "");
-        assert_impl("9", "0");
-        assert_impl("99", "00");
-        assert_impl("999", "001");
-        assert_impl("9999", "1234");
-
-        fn assert_impl(max_number_str: &str, number_str: &str) {
-            let expected_max_number = mock_from_str::<0, 10>(max_number_str);
-            let actual_max_number = mock_from_str::<0, 10>(number_str).max_number_for_current_length();
-            assert_eq!(expected_max_number, actual_max_number);
+    fn offset() {
+        assert_impl::<0, 10>(0, "");
+        assert_impl::<0, 10>(1, "0");
+        assert_impl::<0, 10>(10, "9");
+        assert_impl::<0, 10>(11, "00");
+        assert_impl::<0, 10>(11_111 + 1234, "01234");
+        assert_impl::<2, 10>(0, "00");
+        assert_impl::<0, 21>(
+            11_111_111_111_111_111_111 + (u64::MAX as u128 + 1),
+            "000000000000000000000",
+        );
+
+        fn assert_impl<const A: u8, const B: u8>(expected_offset: u128, number_str: &str) {
+            let number = mock_from_str::<A, B>(number_str);
+            assert_eq!(expected_offset, number.offset(ValueRange::FULL));
+            assert_eq!(
+                number,
+                PaddedNumber::<A, B>::from_offset(expected_offset, ValueRange::FULL)
+            );
         }
     }
 
     #[test]
-    fn max_number_for_max_length() {
-        assert_impl::<0>("");
-        assert_impl::<1>("9");
-        assert_impl::<2>("99");
-
-        fn assert_impl<const B: u8>(expected_max_number_str: &str) {
-            let expected_max_number = mock_from_str::<0, B>(expected_max_number_str);
-            let actual_max_number = PaddedNumber::<0, B>::max_number_for_max_length();
-            assert_eq!(expected_max_number, actual_max_number);
-        }
+    fn bounds_count() {
+        assert_eq!(1, PaddedNumber::<0, 0>::bounds_count(ValueRange::FULL));
+        assert_eq!(10, PaddedNumber::<1, 1>::bounds_count(ValueRange::FULL));
+        assert_eq!(1110, PaddedNumber::<1, 3>::bounds_count(ValueRange::FULL));
+        assert_eq!(
+            2 * (u64::MAX as u128 + 1),
+            PaddedNumber::<20, 21>::bounds_count(ValueRange::FULL)
+        );
+        assert_eq!(
+            11_111_111_111_111_111_110 + 236 * (u64::MAX as u128 + 1),
+            PaddedNumber::<1, 255>::COUNT
+        );
     }
 
     #[test]
@@ -269,7 +636,13 @@ mod tests {
 
         fn assert_non_overflowing_add(expected: &str, (lhs, rhs): (&str, u64)) {
             assert_arithmetic::<0, 10>(expected, (lhs, rhs), |lhs, rhs| {
-                lhs.add_impl(rhs, |_, _| panic!("overflow occurred when testing non overflows"))
+                lhs.add_impl(
+                    rhs,
+                    OverflowStrategy::Fail,
+                    ValueRange::FULL,
+                    PaddedNumber::<0, 10>::COUNT,
+                )
+                .expect("overflow occurred when testing non overflows")
             });
         }
     }
@@ -327,7 +700,13 @@ mod tests {
 
         fn assert_non_overflowing_sub(expected: &str, (lhs, rhs): (&str, u64)) {
             assert_arithmetic::<0, 10>(expected, (lhs, rhs), |lhs, rhs| {
-                lhs.sub_impl(rhs, |_| panic!("overflow occurred when testing non overflows"))
+                lhs.sub_impl(
+                    rhs,
+                    OverflowStrategy::Fail,
+                    ValueRange::FULL,
+                    PaddedNumber::<0, 10>::COUNT,
+                )
+                .expect("overflow occurred when testing non overflows")
             });
         }
     }
@@ -342,6 +721,8 @@ mod tests {
         assert_wrapping_sub::<1, 3>("995", ("01", 16));
         // wrappes at empty
         assert_wrapping_sub::<0, 0>("", ("", 10));
+        // empty is left as is
+        assert_wrapping_sub::<0, 2>("", ("", 1));
 
         fn assert_wrapping_sub<const A: u8, const B: u8>(expected: &str, (lhs, rhs): (&str, u64)) {
             assert_arithmetic(expected, (lhs, rhs), PaddedNumber::<A, B>::wrapping_sub);
@@ -364,6 +745,151 @@ mod tests {
         }
     }
 
+    #[test]
+    fn checked_arithmetic() {
+        let min = mock_from_str::<1, 2>("0");
+        let max = mock_from_str::<1, 2>("99");
+
+        assert_eq!(Some(max), min.checked_add(109));
+        assert_eq!(None, min.checked_add(110));
+        assert_eq!(Some(min), max.checked_sub(109));
+        assert_eq!(None, max.checked_sub(110));
+    }
+
+    #[test]
+    fn steps_to() {
+        let min = mock_from_str::<1, 2>("0");
+        let max = mock_from_str::<1, 2>("99");
+
+        assert_eq!(Some(109), min.steps_to(max));
+        assert_eq!(Some(0), max.steps_to(max));
+        assert_eq!(None, max.steps_to(min));
+
+        let first = mock_from_str::<0, 21>("");
+        let last = mock_from_str::<0, 21>("018446744073709551615");
+        assert_eq!(Some(PaddedNumber::<0, 21>::COUNT - 1), first.steps_to(last));
+    }
+
+    #[test]
+    fn large_rhs() {
+        assert_wrapping::<1, 1>("5", ("0", u64::MAX));
+        assert_wrapping::<20, 20>("18446744073709551615", ("00000000000000000000", u64::MAX));
+        assert_eq!(
+            mock_from_str::<1, 3>("999"),
+            mock_from_str::<1, 3>("0").saturating_add(u64::MAX)
+        );
+        assert_eq!(
+            mock_from_str::<1, 3>("0"),
+            mock_from_str::<1, 3>("999").saturating_sub(u64::MAX)
+        );
+        assert_eq!(
+            mock_from_str::<1, 3>("175"),
+            mock_from_str::<1, 3>("0").wrapping_sub(u64::MAX)
+        );
+
+        fn assert_wrapping<const A: u8, const B: u8>(expected: &str, (lhs, rhs): (&str, u64)) {
+            assert_arithmetic(expected, (lhs, rhs), PaddedNumber::<A, B>::wrapping_add);
+        }
+    }
+
+    #[test]
+    fn unrepresentable_lengths() {
+        // remaining numbers above u64::MAX are skipped
+        let max = mock_from_str::<20, 21>("18446744073709551615");
+        assert_eq!(mock_from_str::<20, 21>("000000000000000000000"), max.wrapping_add(1));
+        assert_eq!(max, max.wrapping_add(1).wrapping_sub(1));
+
+        let last = mock_from_str::<20, 21>("018446744073709551615");
+        assert_eq!(mock_from_str::<20, 21>("00000000000000000000"), last.wrapping_add(1));
+        assert_eq!(None, last.checked_add(1));
+    }
+
+    #[test]
+    fn const_arithmetic() {
+        const START: PaddedNumber<3, 3> = unsafe { PaddedNumber::new_unchecked(2, 9) };
+        const NEXT: PaddedNumber<3, 3> = START.wrapping_add(5);
+        const PREVIOUS: Option<PaddedNumber<3, 3>> = START.checked_sub(10);
+
+        assert_eq!(mock_from_str::<3, 3>("014"), NEXT);
+        assert_eq!(None, PREVIOUS);
+    }
+
+    #[test]
+    fn wrapping_mul() {
+        // preserves length
+        assert_wrapping_mul::<0, 10>("036", ("012", 3));
+        assert_wrapping_mul::<0, 10>("000", ("012", 0));
+        // wraps modulo 10^length
+        assert_wrapping_mul::<0, 10>("98", ("99", 2));
+        assert_wrapping_mul::<0, 10>("0", ("9", 10));
+        assert_wrapping_mul::<0, 10>("", ("", 5));
+        // wraps modulo 2^64 above 19 digits
+        assert_wrapping_mul::<20, 20>("18446744073709551614", ("18446744073709551615", 2));
+
+        fn assert_wrapping_mul<const A: u8, const B: u8>(expected: &str, (lhs, rhs): (&str, u64)) {
+            assert_arithmetic(expected, (lhs, rhs), PaddedNumber::<A, B>::wrapping_mul);
+        }
+    }
+
+    #[test]
+    fn saturating_mul() {
+        assert_saturating_mul::<0, 10>("99", ("50", 2));
+        assert_saturating_mul::<0, 10>("99", ("33", 3));
+        assert_saturating_mul::<0, 10>("999", ("001", u64::MAX));
+        assert_saturating_mul::<20, 20>("18446744073709551615", ("00000000000000000002", u64::MAX));
+
+        fn assert_saturating_mul<const A: u8, const B: u8>(expected: &str, (lhs, rhs): (&str, u64)) {
+            assert_arithmetic(expected, (lhs, rhs), PaddedNumber::<A, B>::saturating_mul);
+        }
+    }
+
+    #[test]
+    fn checked_mul() {
+        let number = mock_from_str::<0, 3>("033");
+
+        assert_eq!(Some(mock_from_str("099")), number.checked_mul(3));
+        assert_eq!(None, number.checked_mul(31));
+        assert_eq!(None, mock_from_str::<0, 3>("1").checked_mul(10));
+        assert_eq!(Some(mock_from_str("")), mock_from_str::<0, 3>("").checked_mul(u64::MAX));
+    }
+
+    #[test]
+    fn div_rem() {
+        let number = mock_from_str::<0, 5>("01234");
+
+        assert_eq!(mock_from_str::<0, 5>("01200"), number / 100 * 100);
+        assert_eq!(mock_from_str::<0, 5>("00002"), number % 7);
+        assert_eq!(mock_from_str::<0, 5>("00000"), number / 10_000);
+        assert_eq!(number, number % 10_000);
+        assert_eq!(mock_from_str::<0, 5>(""), mock_from_str::<0, 5>("") / 3);
+
+        assert_eq!(None, number.checked_div(0));
+        assert_eq!(None, number.checked_rem(0));
+        assert_eq!(number.wrapping_div(3), number.saturating_div(3));
+        assert_eq!(number.wrapping_rem(3), number.saturating_rem(3));
+    }
+
+    #[test]
+    fn mul_diverges_from_repeated_add() {
+        let number = mock_from_str::<1, 2>("5");
+
+        // addition steps through the ordering onto the next length
+        assert_eq!(mock_from_str::<1, 2>("00"), number + 5);
+        // multiplication wraps around within the length
+        assert_eq!(mock_from_str::<1, 2>("0"), number * 2);
+        assert_ne!(number * 2, number + 5);
+
+        // equal while the length is kept
+        let number = mock_from_str::<1, 2>("12");
+        assert_eq!(number * 3, number + 12 + 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = mock_from_str::<0, 5>("01234") / 0;
+    }
+
     fn assert_arithmetic<const A: u8, const B: u8>(
         expected: &str,
         (lhs, rhs): (&str, u64),
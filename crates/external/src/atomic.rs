@@ -0,0 +1,291 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::*;
+
+/// Padded number which can be shared between threads
+///
+/// Holds the ordinal of a [`CompactPaddedNumber`] in an [`AtomicU64`], making
+/// every operation lock-free. The `fetch_add_*` operations follow the
+/// semantics of their [`PaddedNumber`] counterparts exactly, and return
+/// the previous value.
+///
+/// ```rust
+/// # use std::sync::atomic::Ordering;
+/// # use padded_number::*;
+/// let counter = AtomicPaddedNumber::new(bound_padded_number!(2, 3, "998"));
+///
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| counter.fetch_add_wrapping(1, Ordering::Relaxed));
+///     }
+/// });
+///
+/// assert_eq!(
+///     bound_padded_number!(2, 3, "02"),
+///     counter.load(Ordering::Relaxed)
+/// );
+/// ```
+///
+/// Like with `CompactPaddedNumber`, the upper length bound may not exceed 19.
+pub struct AtomicPaddedNumber<const A: u8 = 1, const B: u8 = 19> {
+    ordinal: AtomicU64,
+}
+
+impl<const A: u8, const B: u8> AtomicPaddedNumber<A, B> {
+    /// Create a new [`AtomicPaddedNumber`]
+    pub const fn new(padded_number: PaddedNumber<A, B>) -> Self {
+        Self { ordinal: AtomicU64::new(encode(padded_number)) }
+    }
+
+    /// Consume the atomic and return the contained value
+    pub fn into_inner(self) -> PaddedNumber<A, B> {
+        decode(self.ordinal.into_inner())
+    }
+
+    /// Load the value
+    ///
+    /// See [`AtomicU64::load`] for the accepted orderings.
+    pub fn load(&self, order: Ordering) -> PaddedNumber<A, B> {
+        decode(self.ordinal.load(order))
+    }
+
+    /// Store a value
+    ///
+    /// See [`AtomicU64::store`] for the accepted orderings.
+    pub fn store(&self, padded_number: PaddedNumber<A, B>, order: Ordering) {
+        self.ordinal.store(encode(padded_number), order)
+    }
+
+    /// Store a value, returning the previous value
+    pub fn swap(&self, padded_number: PaddedNumber<A, B>, order: Ordering) -> PaddedNumber<A, B> {
+        decode(self.ordinal.swap(encode(padded_number), order))
+    }
+
+    /// Store `new` if the current value equals `current`
+    ///
+    /// Returns the previous value, wrapped in `Ok` if it was replaced. See
+    /// [`AtomicU64::compare_exchange`] for the accepted orderings.
+    pub fn compare_exchange(
+        &self,
+        current: PaddedNumber<A, B>,
+        new: PaddedNumber<A, B>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<PaddedNumber<A, B>, PaddedNumber<A, B>> {
+        self.ordinal
+            .compare_exchange(encode(current), encode(new), success, failure)
+            .map(decode)
+            .map_err(decode)
+    }
+
+    /// Add to the value as in [`PaddedNumber::wrapping_add`], returning the
+    /// previous value
+    pub fn fetch_add_wrapping(&self, rhs: u64, order: Ordering) -> PaddedNumber<A, B> {
+        self.fetch_update(order, fetch_ordering(order), |number| Some(number.wrapping_add(rhs)))
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Add to the value as in [`PaddedNumber::saturating_add`], returning the
+    /// previous value
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::Ordering;
+    /// # use padded_number::*;
+    /// let counter = AtomicPaddedNumber::new(bound_padded_number!(2, 2, "98"));
+    /// counter.fetch_add_saturating(5, Ordering::Relaxed);
+    /// assert_eq!(
+    ///     bound_padded_number!(2, 2, "99"),
+    ///     counter.load(Ordering::Relaxed)
+    /// );
+    /// ```
+    pub fn fetch_add_saturating(&self, rhs: u64, order: Ordering) -> PaddedNumber<A, B> {
+        self.fetch_update(order, fetch_ordering(order), |number| Some(number.saturating_add(rhs)))
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Add to the value unless it would pass the upper length bound
+    ///
+    /// Returns the previous value, wrapped in `Ok` if the addition took place.
+    /// See [`AtomicU64::fetch_update`] for the accepted orderings.
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::Ordering;
+    /// # use padded_number::*;
+    /// let counter = AtomicPaddedNumber::new(bound_padded_number!(2, 2, "98"));
+    ///
+    /// assert!(
+    ///     counter
+    ///         .fetch_add_checked(1, Ordering::Relaxed, Ordering::Relaxed)
+    ///         .is_ok()
+    /// );
+    /// assert!(
+    ///     counter
+    ///         .fetch_add_checked(1, Ordering::Relaxed, Ordering::Relaxed)
+    ///         .is_err()
+    /// );
+    /// assert_eq!(
+    ///     bound_padded_number!(2, 2, "99"),
+    ///     counter.load(Ordering::Relaxed)
+    /// );
+    /// ```
+    pub fn fetch_add_checked(
+        &self,
+        rhs: u64,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<PaddedNumber<A, B>, PaddedNumber<A, B>> {
+        self.fetch_update(set_order, fetch_order, |number| number.checked_add(rhs))
+    }
+
+    fn fetch_update(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut update_fn: impl FnMut(CompactPaddedNumber<A, B>) -> Option<CompactPaddedNumber<A, B>>,
+    ) -> Result<PaddedNumber<A, B>, PaddedNumber<A, B>> {
+        self.ordinal
+            .fetch_update(set_order, fetch_order, |ordinal| {
+                update_fn(CompactPaddedNumber::from_ordinal_unchecked(ordinal)).map(CompactPaddedNumber::ordinal)
+            })
+            .map(decode)
+            .map_err(decode)
+    }
+}
+
+impl<const A: u8, const B: u8> From<PaddedNumber<A, B>> for AtomicPaddedNumber<A, B> {
+    fn from(padded_number: PaddedNumber<A, B>) -> Self {
+        Self::new(padded_number)
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Debug for AtomicPaddedNumber<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+const fn encode<const A: u8, const B: u8>(padded_number: PaddedNumber<A, B>) -> u64 {
+    CompactPaddedNumber::from_padded_number(padded_number).ordinal()
+}
+
+const fn decode<const A: u8, const B: u8>(ordinal: u64) -> PaddedNumber<A, B> {
+    CompactPaddedNumber::<A, B>::from_ordinal_unchecked(ordinal).into_padded_number()
+}
+
+/// Strongest ordering allowed for the load of a read-modify-write operation
+/// storing with `order`
+const fn fetch_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::AcqRel | Ordering::Acquire => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    #[test]
+    fn load_store() {
+        let atomic = AtomicPaddedNumber::new(mock_from_str::<0, 3>(""));
+        assert_eq!(mock_from_str::<0, 3>(""), atomic.load(Ordering::Relaxed));
+
+        atomic.store(mock_from_str("007"), Ordering::Relaxed);
+        assert_eq!(
+            mock_from_str::<0, 3>("007"),
+            atomic.swap(mock_from_str("7"), Ordering::Relaxed)
+        );
+        assert_eq!(mock_from_str::<0, 3>("7"), atomic.into_inner());
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let atomic = AtomicPaddedNumber::new(mock_from_str::<1, 3>("01"));
+
+        assert_eq!(
+            Err(mock_from_str("01")),
+            atomic.compare_exchange(
+                mock_from_str("1"),
+                mock_from_str("2"),
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            )
+        );
+        assert_eq!(
+            Ok(mock_from_str("01")),
+            atomic.compare_exchange(
+                mock_from_str("01"),
+                mock_from_str("2"),
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            )
+        );
+        assert_eq!(mock_from_str::<1, 3>("2"), atomic.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn fetch_add_matches_padded_number() {
+        for start in ["", "9", "98", "999"] {
+            for rhs in [0, 1, 2, 11, 1111, u64::MAX] {
+                let number = mock_from_str::<0, 3>(start);
+                // already verified to match `PaddedNumber` arithmetic
+                let compact = CompactPaddedNumber::from_padded_number(number);
+
+                let atomic = AtomicPaddedNumber::new(number);
+                assert_eq!(number, atomic.fetch_add_wrapping(rhs, Ordering::Relaxed));
+                assert_eq!(
+                    compact.wrapping_add(rhs).into_padded_number(),
+                    atomic.load(Ordering::Relaxed)
+                );
+
+                let atomic = AtomicPaddedNumber::new(number);
+                assert_eq!(number, atomic.fetch_add_saturating(rhs, Ordering::Relaxed));
+                assert_eq!(
+                    compact.saturating_add(rhs).into_padded_number(),
+                    atomic.load(Ordering::Relaxed)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn concurrent_fetch_add() {
+        const THREADS: u64 = 4;
+        const ADDS: u64 = 1000;
+
+        let start = mock_from_str::<2, 4>("00");
+        let wrapping = AtomicPaddedNumber::new(start);
+        let saturating = AtomicPaddedNumber::new(start);
+        let checked = AtomicPaddedNumber::new(mock_from_str::<2, 4>("9990"));
+        let checked_successes = AtomicU64::new(0);
+
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ADDS {
+                        wrapping.fetch_add_wrapping(7, Ordering::Relaxed);
+                        saturating.fetch_add_saturating(7, Ordering::Relaxed);
+
+                        if checked
+                            .fetch_add_checked(1, Ordering::Relaxed, Ordering::Relaxed)
+                            .is_ok()
+                        {
+                            checked_successes.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                });
+            }
+        });
+
+        let total = THREADS * ADDS * 7;
+        assert_eq!(
+            CompactPaddedNumber::from(start).wrapping_add(total),
+            wrapping.load(Ordering::Relaxed).into()
+        );
+        assert_eq!(mock_from_str::<2, 4>("9999"), saturating.load(Ordering::Relaxed));
+        assert_eq!(mock_from_str::<2, 4>("9999"), checked.load(Ordering::Relaxed));
+        assert_eq!(9, checked_successes.load(Ordering::Relaxed));
+    }
+}
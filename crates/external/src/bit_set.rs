@@ -0,0 +1,403 @@
+use crate::*;
+
+/// Set of padded numbers stored as a bitmap, one bit per value within the
+/// length bounds
+///
+/// Bits are indexed by ordinal, see [`CompactPaddedNumber::ordinal`], making
+/// iteration follow the crate's ordering. Membership checks and updates are a
+/// single bit operation, and set algebra works on 64 values at a time.
+///
+/// The bitmap is allocated up front, taking up one bit for each of the values
+/// within the bounds: 14 KB for `<1, 5>`, but 139 MB for `<1, 9>`. Bounds
+/// spanning more than [`Self::MAX_CAPACITY`] values are rejected at compile
+/// time. Prefer [`PaddedRangeSet`] for sparse sets or larger bounds.
+///
+/// ```rust
+/// # use padded_number::*;
+/// let mut set = PaddedNumberBitSet::<1, 2>::new();
+/// set.insert(bound_padded_number!(1, 2, "00"));
+/// set.insert(bound_padded_number!(1, 2, "9"));
+/// set.insert(bound_padded_number!(1, 2, "10"));
+///
+/// assert_eq!(1, set.rank(bound_padded_number!(1, 2, "00")));
+/// assert_eq!(Some(bound_padded_number!(1, 2, "10")), set.select(2));
+///
+/// let numbers = set
+///     .iter()
+///     .map(|number| number.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(vec!["9", "00", "10"], numbers);
+/// ```
+///
+/// ```compile_fail
+/// # use padded_number::*;
+/// // would allocate 1.4 GB up front
+/// let set = PaddedNumberBitSet::<1, 10>::new();
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PaddedNumberBitSet<const A: u8, const B: u8> {
+    words: Box<[u64]>,
+    len: u64,
+}
+
+impl<const A: u8, const B: u8> PaddedNumberBitSet<A, B> {
+    /// Number of values within the length bounds, and therefore bits within
+    /// the bitmap
+    pub const CAPACITY: u64 = {
+        let count = CompactPaddedNumber::<A, B>::BOUNDS.count;
+        assert!(
+            count <= Self::MAX_CAPACITY,
+            "length bounds of a padded number bit set may not span more than 2^32 values"
+        );
+        count
+    };
+    /// Largest [`Self::CAPACITY`] allowed, taking up 512 MiB
+    pub const MAX_CAPACITY: u64 = 1 << 32;
+
+    /// Create an empty [`PaddedNumberBitSet`]
+    pub fn new() -> Self {
+        let word_count =
+            usize::try_from(Self::CAPACITY.div_ceil(u64::BITS as u64)).expect("bitmap should be addressable");
+
+        Self { words: vec![0; word_count].into_boxed_slice(), len: 0 }
+    }
+
+    /// Create a [`PaddedNumberBitSet`] containing every padded number within
+    /// the length bounds
+    pub fn full() -> Self {
+        Self::new().complement()
+    }
+
+    /// Count the numbers within the set
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Check if the set contains no numbers
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the set contains the number
+    pub fn contains(&self, padded_number: PaddedNumber<A, B>) -> bool {
+        let (word, mask) = position(padded_number);
+        self.words[word] & mask != 0
+    }
+
+    /// Insert a number, returning whether it was newly inserted
+    pub fn insert(&mut self, padded_number: PaddedNumber<A, B>) -> bool {
+        let (word, mask) = position(padded_number);
+        let inserted = self.words[word] & mask == 0;
+
+        self.words[word] |= mask;
+        self.len += inserted as u64;
+
+        inserted
+    }
+
+    /// Remove a number, returning whether it was present
+    pub fn remove(&mut self, padded_number: PaddedNumber<A, B>) -> bool {
+        let (word, mask) = position(padded_number);
+        let removed = self.words[word] & mask != 0;
+
+        self.words[word] &= !mask;
+        self.len -= removed as u64;
+
+        removed
+    }
+
+    /// Remove all numbers
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// Numbers within either set
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Numbers within both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Numbers within this set, but not within the other
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    /// Numbers within exactly one of the sets
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Numbers within the length bounds, but not within the set
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let mut set = PaddedNumberBitSet::<2, 2>::new();
+    /// set.insert(bound_padded_number!(2, 2, "42"));
+    ///
+    /// let complement = set.complement();
+    /// assert!(!complement.contains(bound_padded_number!(2, 2, "42")));
+    /// assert_eq!(99, complement.len());
+    /// ```
+    pub fn complement(&self) -> Self {
+        let mut complement = self.combine(self, |a, _| !a);
+
+        // clear the bits past the last value
+        let used_bits = Self::CAPACITY % u64::BITS as u64;
+        if let Some(last) = complement.words.last_mut()
+            && used_bits != 0
+        {
+            *last &= (1 << used_bits) - 1;
+        }
+
+        complement.len = Self::CAPACITY - self.len;
+        complement
+    }
+
+    /// Check if every number within the set also is within the other
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & !b == 0)
+    }
+
+    /// Check if the sets have no numbers in common
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// Count the numbers within the set which are less than the provided one
+    pub fn rank(&self, padded_number: PaddedNumber<A, B>) -> u64 {
+        let (word, mask) = position(padded_number);
+
+        let preceding_words = self.words[..word]
+            .iter()
+            .map(|word| word.count_ones() as u64)
+            .sum::<u64>();
+        let preceding_bits = (self.words[word] & (mask - 1)).count_ones() as u64;
+
+        preceding_words + preceding_bits
+    }
+
+    /// Get the number at the provided position within the set, counting from
+    /// zero in ascending order
+    ///
+    /// Inverse of [`Self::rank`] for numbers within the set.
+    pub fn select(&self, mut index: u64) -> Option<PaddedNumber<A, B>> {
+        for (word_index, &word) in self.words.iter().enumerate() {
+            let count = word.count_ones() as u64;
+
+            if index < count {
+                let bit = SetBits(word).nth(index as usize)?;
+                return Some(decode(word_index, bit));
+            }
+
+            index -= count;
+        }
+
+        None
+    }
+
+    /// Iterate over the numbers of the set, in ascending order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = PaddedNumber<A, B>> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| SetBits(word).map(move |bit| decode(word_index, bit)))
+    }
+
+    fn combine(&self, other: &Self, operation: impl Fn(u64, u64) -> u64) -> Self {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(&a, &b)| operation(a, b))
+            .collect::<Box<[u64]>>();
+
+        let len = words.iter().map(|word| word.count_ones() as u64).sum();
+
+        Self { words, len }
+    }
+}
+
+impl<const A: u8, const B: u8> Default for PaddedNumberBitSet<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const A: u8, const B: u8> FromIterator<PaddedNumber<A, B>> for PaddedNumberBitSet<A, B> {
+    fn from_iter<I: IntoIterator<Item = PaddedNumber<A, B>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const A: u8, const B: u8> Extend<PaddedNumber<A, B>> for PaddedNumberBitSet<A, B> {
+    fn extend<I: IntoIterator<Item = PaddedNumber<A, B>>>(&mut self, iter: I) {
+        for padded_number in iter {
+            self.insert(padded_number);
+        }
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Debug for PaddedNumberBitSet<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Word index and bit mask of a number
+fn position<const A: u8, const B: u8>(padded_number: PaddedNumber<A, B>) -> (usize, u64) {
+    let index =
+        CompactPaddedNumber::from_padded_number(padded_number).ordinal() - CompactPaddedNumber::<A, B>::BOUNDS.start;
+
+    ((index / u64::BITS as u64) as usize, 1 << (index % u64::BITS as u64))
+}
+
+fn decode<const A: u8, const B: u8>(word_index: usize, bit: u32) -> PaddedNumber<A, B> {
+    let index = word_index as u64 * u64::BITS as u64 + bit as u64;
+    CompactPaddedNumber::<A, B>::from_ordinal_unchecked(CompactPaddedNumber::<A, B>::BOUNDS.start + index)
+        .into_padded_number()
+}
+
+/// Positions of the set bits within a word, in ascending order
+struct SetBits(u64);
+
+impl Iterator for SetBits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let bit = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+}
+
+impl DoubleEndedIterator for SetBits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let bit = u64::BITS - 1 - self.0.leading_zeros();
+        self.0 &= !(1 << bit);
+        Some(bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    fn set(numbers: &[&str]) -> PaddedNumberBitSet<0, 3> {
+        numbers.iter().map(|str| mock_from_str(str)).collect()
+    }
+
+    fn strings(set: &PaddedNumberBitSet<0, 3>) -> Vec<String> {
+        set.iter().map(|number| number.to_string()).collect()
+    }
+
+    #[test]
+    fn capacity() {
+        assert_eq!(1111, PaddedNumberBitSet::<0, 3>::CAPACITY);
+        assert_eq!(111_110, PaddedNumberBitSet::<1, 5>::CAPACITY);
+        assert_eq!(100, PaddedNumberBitSet::<2, 2>::CAPACITY);
+        assert_eq!(1_111_111_110, PaddedNumberBitSet::<1, 9>::CAPACITY);
+    }
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = set(&[]);
+
+        assert!(set.insert(mock_from_str("0")));
+        assert!(!set.insert(mock_from_str("0")));
+        assert!(set.contains(mock_from_str("0")));
+        assert!(!set.contains(mock_from_str("00")));
+        assert_eq!(1, set.len());
+
+        assert!(set.remove(mock_from_str("0")));
+        assert!(!set.remove(mock_from_str("0")));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn iterates_in_crate_order() {
+        let set = set(&["999", "", "00", "9", "10", "000"]);
+
+        assert_eq!(vec!["", "9", "00", "10", "000", "999"], strings(&set));
+        assert_eq!(Some(mock_from_str("999")), set.iter().next_back());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&["0", "00", "000"]);
+        let b = set(&["00", "000", "999"]);
+
+        assert_eq!(vec!["0", "00", "000", "999"], strings(&a.union(&b)));
+        assert_eq!(vec!["00", "000"], strings(&a.intersection(&b)));
+        assert_eq!(vec!["0"], strings(&a.difference(&b)));
+        assert_eq!(vec!["0", "999"], strings(&a.symmetric_difference(&b)));
+
+        assert!(a.intersection(&b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+        assert!(a.difference(&b).is_disjoint(&b));
+        assert_eq!(4, a.union(&b).len());
+    }
+
+    #[test]
+    fn complement() {
+        let set = set(&["", "999"]);
+        let complement = set.complement();
+
+        assert_eq!(1109, complement.len());
+        assert_eq!(1109, complement.iter().count());
+        assert!(!complement.contains(mock_from_str("999")));
+        assert!(complement.contains(mock_from_str("998")));
+
+        assert_eq!(PaddedNumberBitSet::full(), set.union(&complement));
+    }
+
+    #[test]
+    fn rank_select() {
+        let set = set(&["5", "00", "01", "123"]);
+
+        for (index, number) in set.iter().enumerate() {
+            assert_eq!(index as u64, set.rank(number));
+            assert_eq!(Some(number), set.select(index as u64));
+        }
+
+        assert_eq!(0, set.rank(mock_from_str("")));
+        assert_eq!(3, set.rank(mock_from_str("100")));
+        assert_eq!(4, set.rank(mock_from_str("999")));
+        assert_eq!(None, set.select(4));
+    }
+
+    #[test]
+    fn matches_btree_set() {
+        let numbers = (0..1000_u64)
+            .map(|index| index * 7919 % PaddedNumberBitSet::<1, 5>::CAPACITY)
+            .map(|index| {
+                CompactPaddedNumber::<1, 5>::from_ordinal(index + 1)
+                    .unwrap()
+                    .into_padded_number()
+            })
+            .collect::<Vec<_>>();
+
+        let bit_set = numbers.iter().copied().collect::<PaddedNumberBitSet<1, 5>>();
+        let btree_set = numbers.iter().copied().collect::<BTreeSet<_>>();
+
+        assert_eq!(btree_set.len() as u64, bit_set.len());
+        assert!(bit_set.iter().eq(btree_set.iter().copied()));
+    }
+}
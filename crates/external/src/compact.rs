@@ -0,0 +1,389 @@
+use std::{
+    num::NonZeroU64,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    ordinal::{MAX_ORDINAL_LENGTH, OrdinalBounds},
+    *,
+};
+
+/// Padded number stored in a single `u64`
+///
+/// Holds the same values as a [`PaddedNumber`], but encodes them by
+/// their rank in the crate's ordering rather than as a leading zeros count and
+/// a remaining number: `""` => 0, `"0"` => 1, `"9"` => 10, `"00"` => 11, and so
+/// on. All padded numbers of up to 19 digits fit into an `u64` this way, with
+/// room to spare for a niche. `CompactPaddedNumber` is therefore 8 bytes large,
+/// and so is an `Option<CompactPaddedNumber>`.
+///
+/// ```rust
+/// # use padded_number::*;
+/// assert_eq!(8, size_of::<CompactPaddedNumber>());
+/// assert_eq!(8, size_of::<Option<CompactPaddedNumber>>());
+/// ```
+///
+/// Comparisons, hashing and arithmetic reduce to plain integer operations, and
+/// otherwise behave exactly as they do for [`PaddedNumber`].
+///
+/// Length bounds are set in the same manner as for `PaddedNumber`, with the
+/// exception that the upper bound may not exceed 19. Using a larger one
+/// results in a compile time error once the type is used.
+///
+/// ```compile_fail
+/// # use padded_number::*;
+/// let number = CompactPaddedNumber::<1, 20>::try_new("1");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct CompactPaddedNumber<const A: u8 = 1, const B: u8 = 19> {
+    // ordinal + 1, leaving zero as niche
+    encoded: NonZeroU64,
+}
+
+impl<const A: u8, const B: u8> CompactPaddedNumber<A, B> {
+    pub(crate) const BOUNDS: OrdinalBounds = {
+        assert!(
+            B <= MAX_ORDINAL_LENGTH,
+            "max length bound for a compact padded number may not exceed 19"
+        );
+        OrdinalBounds::new(A, B)
+    };
+
+    /// Create a new [`CompactPaddedNumber`]
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = CompactPaddedNumber::<2, 3>::try_new("012").unwrap();
+    /// assert_eq!("012", number.to_string());
+    ///
+    /// assert!(CompactPaddedNumber::<2, 3>::try_new("0").is_err());
+    /// ```
+    pub const fn try_new(str: &str) -> Result<Self, ParsePaddedNumberError> {
+        match PaddedNumber::try_new(str) {
+            Ok(padded_number) => Ok(Self::from_padded_number(padded_number)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Compact a [`PaddedNumber`] with the same bounds
+    pub const fn from_padded_number(padded_number: PaddedNumber<A, B>) -> Self {
+        Self::from_ordinal_unchecked(ordinal::from_parts(padded_number.leading_zeros, padded_number.number))
+    }
+
+    /// Expand into a [`PaddedNumber`] with the same bounds
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let padded_number = bound_padded_number!(1, 5, "0042");
+    /// let compact = CompactPaddedNumber::from_padded_number(padded_number);
+    /// assert_eq!(padded_number, compact.into_padded_number());
+    /// ```
+    pub const fn into_padded_number(self) -> PaddedNumber<A, B> {
+        let (leading_zeros, number) = ordinal::into_parts(self.ordinal());
+        PaddedNumber { leading_zeros, number }
+    }
+
+    /// Rank of the number within the crate's ordering, counting from `""` as 0
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let ordinal = |str| CompactPaddedNumber::<0, 3>::try_new(str).unwrap().ordinal();
+    /// assert_eq!(0, ordinal(""));
+    /// assert_eq!(1, ordinal("0"));
+    /// assert_eq!(10, ordinal("9"));
+    /// assert_eq!(11, ordinal("00"));
+    /// ```
+    pub const fn ordinal(self) -> u64 {
+        self.encoded.get() - 1
+    }
+
+    /// Create a [`CompactPaddedNumber`] from its ordinal
+    ///
+    /// Returns `None` if the ordinal lies outside of the length bounds.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// assert_eq!(
+    ///     "00",
+    ///     CompactPaddedNumber::<1, 2>::from_ordinal(11)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert!(CompactPaddedNumber::<1, 2>::from_ordinal(0).is_none());
+    /// ```
+    pub const fn from_ordinal(ordinal: u64) -> Option<Self> {
+        match Self::BOUNDS.contains(ordinal) {
+            true => Some(Self::from_ordinal_unchecked(ordinal)),
+            false => None,
+        }
+    }
+
+    /// Like [`Self::from_ordinal`], but with an error describing why the
+    /// ordinal lies outside of the length bounds.
+    pub(crate) const fn try_from_ordinal(ordinal: u64) -> Result<Self, DecodePaddedNumberError> {
+        if ordinal >= ordinal::length_offset(MAX_ORDINAL_LENGTH + 1) {
+            return Err(DecodePaddedNumberError::TooLong(B, MAX_ORDINAL_LENGTH + 1));
+        }
+
+        let (leading_zeros, number) = ordinal::into_parts(ordinal);
+        let length = leading_zeros + utils::number_len(number);
+
+        if length < A {
+            return Err(DecodePaddedNumberError::TooShort(A, length));
+        }
+
+        if length > B {
+            return Err(DecodePaddedNumberError::TooLong(B, length));
+        }
+
+        Ok(Self::from_ordinal_unchecked(ordinal))
+    }
+
+    /// Calculate the length of the padded number, including any leading zeros
+    pub const fn len(&self) -> u8 {
+        self.into_padded_number().len()
+    }
+
+    /// Check if the number if empty, e.g. if and only if it is `""`.
+    pub const fn is_empty(&self) -> bool {
+        self.ordinal() == 0
+    }
+
+    /// Wrapping addition with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::wrapping_add`], and used within the `impl
+    /// Add<u64> for CompactPaddedNumber` implementation.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = CompactPaddedNumber::<2, 3>::try_new("999").unwrap();
+    /// assert_eq!("01", number.wrapping_add(2).to_string());
+    /// ```
+    pub const fn wrapping_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Wrap)
+            .expect("wrapping never fails")
+    }
+
+    /// Saturating addition with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::saturating_add`].
+    pub const fn saturating_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Saturate)
+            .expect("saturating never fails")
+    }
+
+    /// Checked addition with u64 as right-hand side
+    ///
+    /// Returns `None` instead of wrapping past the upper length bound.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = CompactPaddedNumber::<1, 2>::try_new("98").unwrap();
+    /// assert_eq!("99", number.checked_add(1).unwrap().to_string());
+    /// assert!(number.checked_add(2).is_none());
+    /// ```
+    pub const fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.add_impl(rhs, OverflowStrategy::Fail)
+    }
+
+    /// Wrapping subtraction with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::wrapping_sub`], and used within the `impl
+    /// Sub<u64> for CompactPaddedNumber` implementation.
+    pub const fn wrapping_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Wrap)
+            .expect("wrapping never fails")
+    }
+
+    /// Saturating subtraction with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::saturating_sub`].
+    pub const fn saturating_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Saturate)
+            .expect("saturating never fails")
+    }
+
+    /// Checked subtraction with u64 as right-hand side
+    ///
+    /// Returns `None` instead of wrapping past the lower length bound.
+    pub const fn checked_sub(self, rhs: u64) -> Option<Self> {
+        self.sub_impl(rhs, OverflowStrategy::Fail)
+    }
+
+    const fn add_impl(self, rhs: u64, strategy: OverflowStrategy) -> Option<Self> {
+        match Self::BOUNDS.add(self.ordinal(), rhs, strategy) {
+            Some(ordinal) => Some(Self::from_ordinal_unchecked(ordinal)),
+            None => None,
+        }
+    }
+
+    const fn sub_impl(self, rhs: u64, strategy: OverflowStrategy) -> Option<Self> {
+        // nothing to subtract from, as with `PaddedNumber`
+        if self.is_empty() {
+            return Some(self);
+        }
+
+        match Self::BOUNDS.sub(self.ordinal(), rhs, strategy) {
+            Some(ordinal) => Some(Self::from_ordinal_unchecked(ordinal)),
+            None => None,
+        }
+    }
+
+    pub(crate) const fn from_ordinal_unchecked(ordinal: u64) -> Self {
+        // ensures that the bounds are checked for any constructed number
+        let _bounds = Self::BOUNDS;
+
+        match NonZeroU64::new(ordinal + 1) {
+            Some(encoded) => Self { encoded },
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<const A: u8, const B: u8> From<PaddedNumber<A, B>> for CompactPaddedNumber<A, B> {
+    fn from(padded_number: PaddedNumber<A, B>) -> Self {
+        Self::from_padded_number(padded_number)
+    }
+}
+
+impl<const A: u8, const B: u8> From<CompactPaddedNumber<A, B>> for PaddedNumber<A, B> {
+    fn from(compact: CompactPaddedNumber<A, B>) -> Self {
+        compact.into_padded_number()
+    }
+}
+
+impl<const A: u8, const B: u8> FromStr for CompactPaddedNumber<A, B> {
+    type Err = ParsePaddedNumberError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::try_new(str)
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Debug for CompactPaddedNumber<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.into_padded_number(), f)
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Display for CompactPaddedNumber<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.into_padded_number(), f)
+    }
+}
+
+impl<const A: u8, const B: u8> Add<u64> for CompactPaddedNumber<A, B> {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self::wrapping_add(self, rhs)
+    }
+}
+
+impl<const A: u8, const B: u8> Sub<u64> for CompactPaddedNumber<A, B> {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self::wrapping_sub(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    #[test]
+    fn size() {
+        assert_eq!(8, size_of::<CompactPaddedNumber>());
+        assert_eq!(8, size_of::<Option<CompactPaddedNumber>>());
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip::<0, 0>("");
+        assert_round_trip::<0, 3>("");
+        assert_round_trip::<1, 3>("000");
+        assert_round_trip::<1, 19>("0001234");
+        assert_round_trip::<19, 19>("9999999999999999999");
+
+        fn assert_round_trip<const A: u8, const B: u8>(number_str: &str) {
+            let padded_number = mock_from_str::<A, B>(number_str);
+            let compact = CompactPaddedNumber::from(padded_number);
+
+            assert_eq!(padded_number, PaddedNumber::from(compact));
+            assert_eq!(number_str, compact.to_string());
+        }
+    }
+
+    #[test]
+    fn from_ordinal_respects_bounds() {
+        assert!(CompactPaddedNumber::<2, 3>::from_ordinal(10).is_none());
+        assert!(CompactPaddedNumber::<2, 3>::from_ordinal(11).is_some());
+        assert!(CompactPaddedNumber::<2, 3>::from_ordinal(1110).is_some());
+        assert!(CompactPaddedNumber::<2, 3>::from_ordinal(1111).is_none());
+    }
+
+    #[test]
+    fn ordering_matches_padded_number() {
+        assert_ordering("", "0");
+        assert_ordering("9", "00");
+        assert_ordering("10", "001");
+        assert_ordering("0012", "0120");
+
+        fn assert_ordering(number_str_0: &str, number_str_1: &str) {
+            let number_0 = CompactPaddedNumber::<0, 10>::try_new(number_str_0).unwrap();
+            let number_1 = CompactPaddedNumber::<0, 10>::try_new(number_str_1).unwrap();
+            assert!(number_0 < number_1);
+        }
+    }
+
+    #[test]
+    fn arithmetic_matches_padded_number() {
+        assert_arithmetic::<0, 0>();
+        assert_arithmetic::<0, 2>();
+        assert_arithmetic::<1, 1>();
+        assert_arithmetic::<1, 2>();
+        assert_arithmetic::<2, 3>();
+
+        fn assert_arithmetic<const A: u8, const B: u8>() {
+            let bounds = CompactPaddedNumber::<A, B>::BOUNDS;
+
+            for ordinal in bounds.start..=bounds.end() {
+                let compact = CompactPaddedNumber::<A, B>::from_ordinal(ordinal).unwrap();
+                let padded_number = compact.into_padded_number();
+
+                for rhs in 0..=(bounds.count + 1) {
+                    assert_eq!(
+                        padded_number.wrapping_add(rhs),
+                        compact.wrapping_add(rhs).into_padded_number()
+                    );
+                    assert_eq!(
+                        padded_number.saturating_add(rhs),
+                        compact.saturating_add(rhs).into_padded_number()
+                    );
+                    assert_eq!(
+                        padded_number.wrapping_sub(rhs),
+                        compact.wrapping_sub(rhs).into_padded_number()
+                    );
+                    assert_eq!(
+                        padded_number.saturating_sub(rhs),
+                        compact.saturating_sub(rhs).into_padded_number()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let min = CompactPaddedNumber::<1, 2>::try_new("0").unwrap();
+        let max = CompactPaddedNumber::<1, 2>::try_new("99").unwrap();
+
+        assert_eq!(Some(max), min.checked_add(109));
+        assert_eq!(None, min.checked_add(110));
+        assert_eq!(Some(min), max.checked_sub(109));
+        assert_eq!(None, max.checked_sub(110));
+    }
+}
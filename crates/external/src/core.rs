@@ -16,7 +16,17 @@ use crate::*;
 /// - `MIN == 0` results in empty values ("") being allowed as valid numbers.
 /// - `MIN > MAX, where MIN, MAX > 0` is technically declarable, but any
 ///   attempts at constructing such a padded number will fail.
-#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+///
+/// Padded numbers compare equal and are ordered by their digits, regardless of
+/// their length bounds. As equality is implemented by hand to allow this, they
+/// can not be used as patterns in `match` expressions. Compare them with `==`
+/// in match guards instead.
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow),
+    diesel(sql_type = ::diesel::sql_types::Text)
+)]
 pub struct PaddedNumber<const A: u8 = 1, const B: u8 = { u8::MAX }> {
     pub(crate) leading_zeros: u8,
     pub(crate) number: u64,
@@ -28,6 +38,25 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
         Self { leading_zeros, number }
     }
 
+    /// Used by the macros to validate inputs against bounds which aren't known
+    /// until const evaluation, e.g. when inferred from the expected type. Fails
+    /// to compile when evaluated in a const block.
+    #[doc(hidden)]
+    pub const fn new_or_panic(str: &str) -> Self {
+        match Self::try_new(str) {
+            Ok(padded_number) => padded_number,
+            Err(ParsePaddedNumberError::TooShort(_, _)) => {
+                panic!("too few digits provided for the `PaddedNumber` length bounds")
+            }
+            Err(ParsePaddedNumberError::TooLong(_, _)) => {
+                panic!("too many digits provided for the `PaddedNumber` length bounds")
+            }
+            Err(ParsePaddedNumberError::InvalidNumber(_)) => {
+                panic!("integer parse error, encountered non-ascii digit")
+            }
+        }
+    }
+
     /// Create a new [`PaddedNumber`]
     pub const fn try_new(str: &str) -> Result<Self, ParsePaddedNumberError> {
         let (leading_zeros, remaining_number) = konst::try_!(padded_number_internal::parse(A, B, str));
@@ -35,12 +64,35 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
         Ok(Self { leading_zeros, number: remaining_number })
     }
 
+    /// Create a new [`PaddedNumber`] from its length and remaining
+    /// number
+    pub(crate) const fn try_from_parts(length: u8, number: u64) -> Result<Self, DecodePaddedNumberError> {
+        let number_length = utils::number_len(number);
+
+        if number_length > length {
+            return Err(DecodePaddedNumberError::NumberTooLong(length, number));
+        }
+
+        if length < A {
+            return Err(DecodePaddedNumberError::TooShort(A, length));
+        }
+
+        if length > B {
+            return Err(DecodePaddedNumberError::TooLong(B, length));
+        }
+
+        Ok(Self { leading_zeros: length - number_length, number })
+    }
+
     /// Calculate the length of the padded number, including any leading zeros
     ///
     /// ```rust
     /// # use padded_number::*;
-    /// assert_eq!(2, padded_number!("01").len());
-    /// assert_eq!(3, padded_number!("123").len());
+    /// let number: PaddedNumber = padded_number!("01");
+    /// assert_eq!(2, number.len());
+    ///
+    /// let number: PaddedNumber = padded_number!("123");
+    /// assert_eq!(3, number.len());
     /// ```
     pub const fn len(&self) -> u8 {
         self.leading_zeros + utils::number_len(self.number)
@@ -50,11 +102,24 @@ impl<const A: u8, const B: u8> PaddedNumber<A, B> {
     /// ```rust
     /// # use padded_number::*;
     /// assert!(bound_padded_number!(0, 1, "").is_empty());
-    /// assert!(!padded_number!("01").is_empty());
+    /// assert!(!bound_padded_number!(0, 2, "01").is_empty());
     /// ```
     pub const fn is_empty(&self) -> bool {
         self.leading_zeros == 0 && self.number == 0
     }
+
+    /// # Panics
+    /// - If index >= length
+    pub(crate) const fn digit(&self, index: u8) -> u8 {
+        if index < self.leading_zeros {
+            return 0;
+        }
+
+        let number_length = utils::number_len(self.number);
+        let remaining_shifts = number_length - 1 - (index - self.leading_zeros);
+
+        ((self.number / 10_u64.pow(remaining_shifts as u32)) % 10) as u8
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +136,7 @@ mod tests {
     #[test]
     fn new_with_leading_zeros_only() {
         let number = mock_from_str::<1, 3>("000");
-        let expected = PaddedNumber { leading_zeros: 3, number: 0 };
+        let expected = PaddedNumber::<1, 3> { leading_zeros: 3, number: 0 };
         assert_eq!(expected, number)
     }
 
@@ -114,6 +179,13 @@ mod tests {
         assert!(number.is_empty())
     }
 
+    #[test]
+    fn digits() {
+        let number = mock_from_str::<0, 10>("00120");
+        let digits = (0..number.len()).map(|index| number.digit(index)).collect::<Vec<_>>();
+        assert_eq!(vec![0, 0, 1, 2, 0], digits);
+    }
+
     #[test]
     fn length() {
         assert_len(0, "");
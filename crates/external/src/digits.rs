@@ -0,0 +1,224 @@
+use std::{borrow::Borrow, cmp::Ordering, hash::Hash, ops::Deref, str::FromStr};
+
+use crate::*;
+
+/// Exact width padded number stored as its ASCII digits
+///
+/// Unlike [`PaddedNumber`], which keeps track of a number,
+/// `PaddedDigits` is backed by a `[u8; N]` array. It can therefore hand out a
+/// `&str` without any allocation, and dereferences to one.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// # use padded_number::*;
+/// let digits = PaddedDigits::<4>::try_new("0042").unwrap();
+/// assert_eq!("0042", digits.as_str());
+/// assert!(digits.starts_with("00"));
+/// ```
+///
+/// It also implements `Borrow<str>`, so that maps and sets keyed by
+/// `PaddedDigits` can be queried with a `&str`.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// # use std::collections::HashMap;
+/// # use padded_number::*;
+/// let mut map = HashMap::new();
+/// map.insert(PaddedDigits::<3>::try_new("007").unwrap(), "bond");
+/// assert_eq!(Some(&"bond"), map.get("007"));
+/// ```
+///
+/// Converts losslessly to and from a `PaddedNumber<N, N>`.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// # use padded_number::*;
+/// let padded_number = bound_padded_number!(3, 3, "001");
+/// let digits = PaddedDigits::from_padded_number(padded_number);
+/// assert_eq!(padded_number, digits.into_padded_number());
+/// ```
+#[derive(Clone, Copy)]
+pub struct PaddedDigits<const N: u8>
+where
+    [(); N as usize]:,
+{
+    digits: [u8; N as usize],
+}
+
+impl<const N: u8> PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    /// Create new [`PaddedDigits`]
+    ///
+    /// Errors if the string is not exactly `N` ASCII digits long.
+    pub const fn try_new(str: &str) -> Result<Self, ParsePaddedNumberError> {
+        match PaddedNumber::<N, N>::try_new(str) {
+            Ok(padded_number) => Ok(Self::from_padded_number(padded_number)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Write out the digits of a [`PaddedNumber`] of the same width
+    pub const fn from_padded_number(padded_number: PaddedNumber<N, N>) -> Self {
+        let mut digits = [b'0'; N as usize];
+        utils::write_ascii_digits(padded_number.number, &mut digits);
+        Self { digits }
+    }
+
+    /// Convert back into a [`PaddedNumber`] of the same width
+    pub const fn into_padded_number(self) -> PaddedNumber<N, N> {
+        match PaddedNumber::try_new(self.as_str()) {
+            Ok(padded_number) => padded_number,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Extract a string slice of the digits
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: digits only ever contain ASCII digits
+        unsafe { std::str::from_utf8_unchecked(&self.digits) }
+    }
+
+    /// Extract a byte slice of the ASCII digits
+    pub const fn as_bytes(&self) -> &[u8; N as usize] {
+        &self.digits
+    }
+}
+
+impl<const N: u8> Deref for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: u8> AsRef<str> for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: u8> Borrow<str> for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// `Borrow<str>` requires `Eq`, `Ord` and `Hash` to agree with those of `str`
+
+impl<const N: u8> PartialEq for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: u8> Eq for PaddedDigits<N> where [(); N as usize]: {}
+
+impl<const N: u8> PartialEq<str> for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: u8> PartialEq<&str> for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: u8> Ord for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: u8> PartialOrd for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: u8> Hash for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: u8> std::fmt::Debug for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: u8> std::fmt::Display for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: u8> FromStr for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    type Err = ParsePaddedNumberError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::try_new(str)
+    }
+}
+
+impl<const N: u8> From<PaddedNumber<N, N>> for PaddedDigits<N>
+where
+    [(); N as usize]:,
+{
+    fn from(padded_number: PaddedNumber<N, N>) -> Self {
+        Self::from_padded_number(padded_number)
+    }
+}
+
+impl<const N: u8> From<PaddedDigits<N>> for PaddedNumber<N, N>
+where
+    [(); N as usize]:,
+{
+    fn from(padded_digits: PaddedDigits<N>) -> Self {
+        padded_digits.into_padded_number()
+    }
+}
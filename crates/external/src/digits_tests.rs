@@ -0,0 +1,59 @@
+//! NOTE: not under feature flagged digits module to ensure tests are run with
+//! `cargo test`
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{tests::mock_from_str, *};
+
+#[test]
+fn round_trip() {
+    assert_round_trip::<0>("");
+    assert_round_trip::<1>("0");
+    assert_round_trip::<4>("0000");
+    assert_round_trip::<4>("0120");
+    assert_round_trip::<20>("00000000000000000001");
+
+    fn assert_round_trip<const N: u8>(number_str: &str)
+    where
+        [(); N as usize]:,
+    {
+        let padded_number = mock_from_str::<N, N>(number_str);
+        let padded_digits = PaddedDigits::from(padded_number);
+
+        assert_eq!(number_str, padded_digits.as_str());
+        assert_eq!(padded_number, PaddedNumber::from(padded_digits));
+    }
+}
+
+#[test]
+fn wrong_width_error() {
+    assert_eq!(
+        ParsePaddedNumberError::TooShort(3, 2),
+        PaddedDigits::<3>::try_new("01").unwrap_err()
+    );
+    assert_eq!(
+        ParsePaddedNumberError::TooLong(3, 4),
+        PaddedDigits::<3>::try_new("0001").unwrap_err()
+    );
+}
+
+#[test]
+fn str_lookup() {
+    let mut map = HashMap::new();
+    map.insert(PaddedDigits::<2>::try_new("01").unwrap(), 1);
+    map.insert(PaddedDigits::<2>::try_new("10").unwrap(), 10);
+
+    assert_eq!(Some(&1), map.get("01"));
+    assert_eq!(Some(&10), map.get("10"));
+    assert_eq!(None, map.get("1"));
+
+    let set = BTreeSet::from([PaddedDigits::<2>::try_new("01").unwrap()]);
+    assert!(set.contains("01"));
+}
+
+#[test]
+fn display() {
+    let padded_digits = PaddedDigits::<5>::try_new("00120").unwrap();
+    assert_eq!("00120", padded_digits.to_string());
+    assert_eq!("00120", format!("{padded_digits:?}"));
+}
@@ -1,18 +1,18 @@
+use std::fmt::Write;
+
 use super::*;
 
 impl<const A: u8, const B: u8> std::fmt::Debug for PaddedNumber<A, B> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            return Ok(());
+        for _ in 0..self.leading_zeros {
+            f.write_char('0')?;
         }
 
-        let mut string = ['0'].repeat(self.leading_zeros as usize).iter().collect::<String>();
-
         if self.number != 0 {
-            string.push_str(&self.number.to_string());
+            write!(f, "{}", self.number)?;
         }
 
-        f.write_str(&string)
+        Ok(())
     }
 }
 
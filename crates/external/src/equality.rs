@@ -0,0 +1,157 @@
+use std::hash::{Hash, Hasher};
+
+use crate::*;
+
+/// Padded numbers are equal if their digits are, regardless of length bounds
+///
+/// ```rust
+/// # use padded_number::*;
+/// assert_eq!(
+///     bound_padded_number!(1, 3, "001"),
+///     bound_padded_number!(3, 3, "001")
+/// );
+/// assert_ne!(
+///     bound_padded_number!(1, 3, "01"),
+///     bound_padded_number!(1, 5, "001")
+/// );
+/// ```
+///
+/// Being implemented by hand rather than derived, padded numbers can not be
+/// used as patterns:
+///
+/// ```compile_fail
+/// # use padded_number::*;
+/// const EMPTY: PaddedNumber<0, 3> = bound_padded_number!(0, 3, "");
+///
+/// match bound_padded_number!(0, 3, "0") {
+///     EMPTY => {}
+///     _ => {}
+/// }
+/// ```
+impl<const A_0: u8, const B_0: u8, const A_1: u8, const B_1: u8> PartialEq<PaddedNumber<A_1, B_1>>
+    for PaddedNumber<A_0, B_0>
+{
+    fn eq(&self, other: &PaddedNumber<A_1, B_1>) -> bool {
+        self.leading_zeros == other.leading_zeros && self.number == other.number
+    }
+}
+
+impl<const A: u8, const B: u8> Eq for PaddedNumber<A, B> {}
+
+impl<const A: u8, const B: u8> Hash for PaddedNumber<A, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.leading_zeros.hash(state);
+        self.number.hash(state);
+    }
+}
+
+/// Compares against the digit string directly, without first parsing it
+///
+/// ```rust
+/// # use padded_number::*;
+/// let number: PaddedNumber = padded_number!("007");
+/// assert_eq!(number, "007");
+/// assert_ne!(number, "7");
+/// assert_ne!(number, "abc");
+/// ```
+impl<const A: u8, const B: u8> PartialEq<str> for PaddedNumber<A, B> {
+    fn eq(&self, other: &str) -> bool {
+        let bytes = other.as_bytes();
+
+        if bytes.len() != self.len() as usize {
+            return false;
+        }
+
+        let (zeros, digits) = bytes.split_at(self.leading_zeros as usize);
+
+        if zeros.iter().any(|byte| *byte != b'0') {
+            return false;
+        }
+
+        let mut remaining_number = self.number;
+
+        for byte in digits.iter().rev() {
+            if *byte != b'0' + (remaining_number % 10) as u8 {
+                return false;
+            }
+
+            remaining_number /= 10;
+        }
+
+        true
+    }
+}
+
+impl<const A: u8, const B: u8> PartialEq<&str> for PaddedNumber<A, B> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<const A: u8, const B: u8> PartialEq<PaddedNumber<A, B>> for str {
+    fn eq(&self, other: &PaddedNumber<A, B>) -> bool {
+        other == self
+    }
+}
+
+impl<const A: u8, const B: u8> PartialEq<PaddedNumber<A, B>> for &str {
+    fn eq(&self, other: &PaddedNumber<A, B>) -> bool {
+        other == *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    use crate::tests::mock_from_str;
+
+    #[test]
+    fn eq_across_bounds() {
+        assert_eq!(mock_from_str::<1, 3>("001"), mock_from_str::<3, 3>("001"));
+        assert_eq!(mock_from_str::<0, 0>(""), mock_from_str::<0, 10>(""));
+        assert_ne!(mock_from_str::<1, 3>("01"), mock_from_str::<0, 10>("001"));
+    }
+
+    #[test]
+    fn hash_follows_eq() {
+        fn hash(number: impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            number.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(mock_from_str::<1, 3>("001")), hash(mock_from_str::<3, 3>("001")));
+    }
+
+    #[test]
+    fn eq_str() {
+        assert_eq_str("");
+        assert_eq_str("0");
+        assert_eq_str("000");
+        assert_eq_str("0120");
+        assert_eq_str("12345");
+
+        fn assert_eq_str(number_str: &str) {
+            let number = mock_from_str::<0, 10>(number_str);
+            assert_eq!(number, number_str);
+            assert_eq!(number_str, number);
+        }
+    }
+
+    #[test]
+    fn ne_str() {
+        assert_ne_str("0", "");
+        assert_ne_str("0", "00");
+        assert_ne_str("01", "1");
+        assert_ne_str("01", "10");
+        assert_ne_str("01", "0a");
+        assert_ne_str("123", "124");
+        assert_ne_str("100", "1000");
+
+        fn assert_ne_str(number_str: &str, other: &str) {
+            let number = mock_from_str::<0, 10>(number_str);
+            assert_ne!(number, other);
+        }
+    }
+}
@@ -5,35 +5,123 @@
 #![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
 
 #[cfg(feature = "macros")]
-pub use padded_number_macros::{bound_padded_number, padded_number};
+pub use padded_number_macros::{
+    bound_padded_number, include_padded_number_set, include_padded_numbers, padded_number, padded_numbers, padded_range,
+};
 
 mod core;
 pub use core::PaddedNumber;
 
-pub use padded_number_internal::ParsePaddedNumberError;
+/// Derive macros, kept apart from the types they are named after
+#[cfg(feature = "macros")]
+pub mod derive {
+    pub use padded_number_macros::PaddedNumber;
+}
+
+#[cfg(feature = "clap")]
+pub use padded_number_internal::ParsePaddedRangeError;
+#[cfg(feature = "sequence")]
+pub use padded_number_internal::SequenceError;
+pub use padded_number_internal::{
+    DecodePaddedNumberError, ParsePaddedNumberError, ParsePaddedSegmentsError, ParseRangedPaddedNumberError,
+};
+
+mod compact;
+pub use compact::CompactPaddedNumber;
+
+mod ranged;
+pub use ranged::{RangedIter, RangedPaddedNumber};
+
+mod ordering;
+pub use ordering::{ByValue, Lexicographic};
 
 mod arithmetic;
+pub use arithmetic::OverflowStrategy;
+
+mod atomic;
+pub use atomic::AtomicPaddedNumber;
+
+mod range_set;
+pub use range_set::PaddedRangeSet;
+
+mod bit_set;
+pub use bit_set::PaddedNumberBitSet;
+
+mod segments;
+pub use segments::{PaddedSegments, Segments};
+
+mod analysis;
+pub use analysis::{SeriesAnalysis, SeriesFinding};
+
+#[cfg(feature = "macros")]
+mod phf_set;
+#[cfg(feature = "macros")]
+pub use phf_set::PaddedNumberPhfSet;
+
+mod prefix;
+mod prefix_map;
+pub use prefix_map::{PaddedPrefixMap, PrefixIter};
+
 mod display;
+mod equality;
 mod from_str;
-mod ordering;
+mod ordered_bytes;
+mod ordinal;
 
 mod utils;
 
+#[cfg(feature = "clap")]
+mod value_parser;
+#[cfg(feature = "clap")]
+pub use value_parser::{PaddedNumberValueParser, PaddedRangeValueParser};
+#[cfg(test)]
+mod value_parser_tests;
+
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(test)]
 mod serde_tests;
 
+mod schema;
+
+mod sql;
+
+#[cfg(feature = "sequence")]
+mod sequence;
+#[cfg(feature = "sequence")]
+pub use sequence::{PaddedSequence, SequenceBlock};
+#[cfg(test)]
+mod sequence_tests;
+
+#[cfg(feature = "serde_with")]
+pub mod serde_with;
+#[cfg(test)]
+mod serde_with_tests;
+
 #[cfg(feature = "unstable-nightly")]
 mod section;
 #[cfg(test)]
 mod section_tests;
 
+#[cfg(feature = "unstable-nightly")]
+mod digits;
+#[cfg(feature = "unstable-nightly")]
+pub use digits::PaddedDigits;
+#[cfg(test)]
+mod digits_tests;
+
 #[cfg(feature = "unstable-nightly")]
 mod resize;
 #[cfg(feature = "unstable-nightly")]
 pub use resize::ResizePaddedNumber;
 
+/// Used by the `PaddedNumber` derive macro
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+}
+
 // TEMP:
 #[cfg(test)]
 mod tests {
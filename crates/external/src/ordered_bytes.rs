@@ -0,0 +1,149 @@
+use crate::*;
+
+impl<const A: u8, const B: u8> PaddedNumber<A, B> {
+    /// Encode the padded number into bytes whose order matches that of
+    /// `Ord for PaddedNumber`
+    ///
+    /// Consists of the length, followed by the remaining number in big-endian.
+    /// Comparing the returned arrays byte by byte, as ordered key-value stores
+    /// tend to do, yields the same ordering as comparing the padded numbers.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let a: PaddedNumber = padded_number!("10");
+    /// let b: PaddedNumber = padded_number!("001");
+    ///
+    /// assert!(a < b);
+    /// assert!(a.to_ordered_bytes() < b.to_ordered_bytes());
+    /// ```
+    pub const fn to_ordered_bytes(&self) -> [u8; 9] {
+        let [b_0, b_1, b_2, b_3, b_4, b_5, b_6, b_7] = self.number.to_be_bytes();
+        [self.len(), b_0, b_1, b_2, b_3, b_4, b_5, b_6, b_7]
+    }
+
+    /// Decode a padded number from bytes produced by
+    /// [`PaddedNumber::to_ordered_bytes`]
+    ///
+    /// Errors if the encoded length is outside of the length bounds, or if the
+    /// encoded number has more digits than the encoded length.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = bound_padded_number!(2, 4, "0012");
+    /// let bytes = number.to_ordered_bytes();
+    /// assert_eq!(Ok(number), PaddedNumber::from_ordered_bytes(bytes));
+    ///
+    /// assert!(PaddedNumber::<1, 3>::from_ordered_bytes(bytes).is_err());
+    /// ```
+    pub const fn from_ordered_bytes(bytes: [u8; 9]) -> Result<Self, DecodePaddedNumberError> {
+        let [length, b_0, b_1, b_2, b_3, b_4, b_5, b_6, b_7] = bytes;
+        let number = u64::from_be_bytes([b_0, b_1, b_2, b_3, b_4, b_5, b_6, b_7]);
+
+        Self::try_from_parts(length, number)
+    }
+}
+
+impl<const A: u8, const B: u8> CompactPaddedNumber<A, B> {
+    /// Encode the padded number into bytes whose order matches that of
+    /// `Ord for CompactPaddedNumber`
+    ///
+    /// Consists solely of the big-endian ordinal, making it one byte shorter
+    /// than [`PaddedNumber::to_ordered_bytes`].
+    pub const fn to_ordered_bytes(&self) -> [u8; 8] {
+        self.ordinal().to_be_bytes()
+    }
+
+    /// Decode a padded number from bytes produced by
+    /// [`CompactPaddedNumber::to_ordered_bytes`]
+    ///
+    /// Errors if the encoded ordinal lies outside of the length bounds.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = CompactPaddedNumber::<2, 4>::try_new("0012").unwrap();
+    /// let bytes = number.to_ordered_bytes();
+    /// assert_eq!(Ok(number), CompactPaddedNumber::from_ordered_bytes(bytes));
+    /// ```
+    pub const fn from_ordered_bytes(bytes: [u8; 8]) -> Result<Self, DecodePaddedNumberError> {
+        let ordinal = u64::from_be_bytes(bytes);
+
+        Self::try_from_ordinal(ordinal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::*;
+
+    fn padded_number_strategy() -> impl Strategy<Value = PaddedNumber<0, { u8::MAX }>> {
+        let leading_zeros = prop_oneof![0..=3_u8, 0..=(u8::MAX - 20)];
+        let number = prop_oneof![0..1000_u64, any::<u64>()];
+
+        (leading_zeros, number).prop_map(|(leading_zeros, number)| PaddedNumber { leading_zeros, number })
+    }
+
+    fn compact_strategy() -> impl Strategy<Value = CompactPaddedNumber<0, 19>> {
+        "[0-9]{0,19}".prop_map(|number_str| CompactPaddedNumber::try_new(&number_str).unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn order_preserved(lhs in padded_number_strategy(), rhs in padded_number_strategy()) {
+            prop_assert_eq!(lhs.cmp(&rhs), lhs.to_ordered_bytes().cmp(&rhs.to_ordered_bytes()));
+        }
+
+        #[test]
+        fn round_trip(number in padded_number_strategy()) {
+            prop_assert_eq!(Ok(number), PaddedNumber::from_ordered_bytes(number.to_ordered_bytes()));
+        }
+
+        #[test]
+        fn compact_order_preserved(lhs in compact_strategy(), rhs in compact_strategy()) {
+            prop_assert_eq!(lhs.cmp(&rhs), lhs.to_ordered_bytes().cmp(&rhs.to_ordered_bytes()));
+        }
+
+        #[test]
+        fn compact_round_trip(number in compact_strategy()) {
+            prop_assert_eq!(Ok(number), CompactPaddedNumber::from_ordered_bytes(number.to_ordered_bytes()));
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        let bytes = bound_padded_number!(1, 5, "00123").to_ordered_bytes();
+
+        assert_eq!(
+            Err(DecodePaddedNumberError::TooShort(6, 5)),
+            PaddedNumber::<6, 10>::from_ordered_bytes(bytes)
+        );
+        assert_eq!(
+            Err(DecodePaddedNumberError::TooLong(4, 5)),
+            PaddedNumber::<1, 4>::from_ordered_bytes(bytes)
+        );
+
+        let mut invalid_bytes = bytes;
+        invalid_bytes[0] = 2;
+        assert_eq!(
+            Err(DecodePaddedNumberError::NumberTooLong(2, 123)),
+            PaddedNumber::<1, 5>::from_ordered_bytes(invalid_bytes)
+        );
+    }
+
+    #[test]
+    fn compact_decode_errors() {
+        let bytes = CompactPaddedNumber::<1, 5>::try_new("00123")
+            .unwrap()
+            .to_ordered_bytes();
+
+        assert_eq!(
+            Err(DecodePaddedNumberError::TooLong(4, 5)),
+            CompactPaddedNumber::<1, 4>::from_ordered_bytes(bytes)
+        );
+        assert_eq!(
+            Err(DecodePaddedNumberError::TooLong(19, 20)),
+            CompactPaddedNumber::<1, 19>::from_ordered_bytes(u64::MAX.to_be_bytes())
+        );
+    }
+}
@@ -4,15 +4,165 @@ use crate::*;
 
 impl<const A: u8, const B: u8> Ord for PaddedNumber<A, B> {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self.len().cmp(&other.len()) {
-            Ordering::Less => Ordering::Less,
-            Ordering::Greater => Ordering::Greater,
-            Ordering::Equal => self.number.cmp(&other.number),
+        cmp_impl(self, other)
+    }
+}
+
+/// Padded numbers are comparable regardless of their length bounds
+///
+/// ```rust
+/// # use padded_number::*;
+/// assert!(bound_padded_number!(1, 3, "99") < bound_padded_number!(3, 3, "000"));
+/// ```
+impl<const A_0: u8, const B_0: u8, const A_1: u8, const B_1: u8> PartialOrd<PaddedNumber<A_1, B_1>>
+    for PaddedNumber<A_0, B_0>
+{
+    fn partial_cmp(&self, other: &PaddedNumber<A_1, B_1>) -> Option<Ordering> {
+        Some(cmp_impl(self, other))
+    }
+}
+
+fn cmp_impl<const A_0: u8, const B_0: u8, const A_1: u8, const B_1: u8>(
+    lhs: &PaddedNumber<A_0, B_0>,
+    rhs: &PaddedNumber<A_1, B_1>,
+) -> Ordering {
+    match lhs.len().cmp(&rhs.len()) {
+        Ordering::Less => Ordering::Less,
+        Ordering::Greater => Ordering::Greater,
+        Ordering::Equal => lhs.number.cmp(&rhs.number),
+    }
+}
+
+impl<const A: u8, const B: u8> PaddedNumber<A, B> {
+    /// Compare padded numbers by their digit strings
+    ///
+    /// Orders the same way as `str` does, e.g. `"001" < "01" < "1"`, making it
+    /// suitable for matching database collations. Used by [`Lexicographic`].
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let mut numbers: [PaddedNumber; 3] = [
+    ///     padded_number!("1"),
+    ///     padded_number!("01"),
+    ///     padded_number!("001"),
+    /// ];
+    /// numbers.sort_by(PaddedNumber::cmp_lexicographic);
+    ///
+    /// let expected: [PaddedNumber; 3] = [
+    ///     padded_number!("001"),
+    ///     padded_number!("01"),
+    ///     padded_number!("1"),
+    /// ];
+    /// assert_eq!(numbers, expected);
+    /// ```
+    pub fn cmp_lexicographic<const A_1: u8, const B_1: u8>(&self, other: &PaddedNumber<A_1, B_1>) -> Ordering {
+        let common_length = self.len().min(other.len());
+
+        for index in 0..common_length {
+            match self.digit(index).cmp(&other.digit(index)) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+
+    /// Compare padded numbers by their numeric value, with ties broken by
+    /// length
+    ///
+    /// `"001"` and `"1"` have the same value, and `"1"` is therefore ordered
+    /// right before `"001"`. Both are however less than `"2"`. Used by
+    /// [`ByValue`].
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let mut numbers: [PaddedNumber; 3] = [
+    ///     padded_number!("2"),
+    ///     padded_number!("001"),
+    ///     padded_number!("1"),
+    /// ];
+    /// numbers.sort_by(PaddedNumber::cmp_by_value);
+    ///
+    /// let expected: [PaddedNumber; 3] = [
+    ///     padded_number!("1"),
+    ///     padded_number!("001"),
+    ///     padded_number!("2"),
+    /// ];
+    /// assert_eq!(numbers, expected);
+    /// ```
+    pub fn cmp_by_value<const A_1: u8, const B_1: u8>(&self, other: &PaddedNumber<A_1, B_1>) -> Ordering {
+        match self.number.cmp(&other.number) {
+            Ordering::Equal => self.len().cmp(&other.len()),
+            ordering => ordering,
         }
     }
 }
 
-impl<const A: u8, const B: u8> PartialOrd for PaddedNumber<A, B> {
+/// Wrapper ordering padded numbers by their digit strings
+///
+/// See [`PaddedNumber::cmp_lexicographic`] for more.
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use padded_number::*;
+/// let set: BTreeSet<Lexicographic<PaddedNumber>> = BTreeSet::from([
+///     Lexicographic(padded_number!("1")),
+///     Lexicographic(padded_number!("01")),
+///     Lexicographic(padded_number!("001")),
+/// ]);
+///
+/// let ordered = set
+///     .into_iter()
+///     .map(|Lexicographic(number)| number)
+///     .collect::<Vec<_>>();
+/// let expected: [PaddedNumber; 3] = [
+///     padded_number!("001"),
+///     padded_number!("01"),
+///     padded_number!("1"),
+/// ];
+/// assert_eq!(ordered, expected);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lexicographic<T>(pub T);
+
+impl<const A: u8, const B: u8> Ord for Lexicographic<PaddedNumber<A, B>> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_lexicographic(&other.0)
+    }
+}
+
+impl<const A: u8, const B: u8> PartialOrd for Lexicographic<PaddedNumber<A, B>> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Wrapper ordering padded numbers by their numeric value
+///
+/// See [`PaddedNumber::cmp_by_value`] for more.
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use padded_number::*;
+/// let map: BTreeMap<ByValue<PaddedNumber>, char> = BTreeMap::from([
+///     (ByValue(padded_number!("10")), 'a'),
+///     (ByValue(padded_number!("002")), 'b'),
+///     (ByValue(padded_number!("2")), 'c'),
+/// ]);
+///
+/// assert_eq!(map.into_values().collect::<String>(), "cba");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByValue<T>(pub T);
+
+impl<const A: u8, const B: u8> Ord for ByValue<PaddedNumber<A, B>> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_by_value(&other.0)
+    }
+}
+
+impl<const A: u8, const B: u8> PartialOrd for ByValue<PaddedNumber<A, B>> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -20,6 +170,7 @@ impl<const A: u8, const B: u8> PartialOrd for PaddedNumber<A, B> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::tests::mock_from_str;
 
     #[test]
@@ -42,4 +193,57 @@ mod tests {
             assert!(number_1 > number_0);
         }
     }
+
+    #[test]
+    fn lexicographic_ordering() {
+        assert_lexicographic_ordering("", "0");
+        assert_lexicographic_ordering("0", "00");
+        assert_lexicographic_ordering("001", "01");
+        assert_lexicographic_ordering("01", "1");
+        assert_lexicographic_ordering("001", "1");
+        assert_lexicographic_ordering("10", "9");
+        assert_lexicographic_ordering("12", "120");
+        assert_lexicographic_ordering("0120", "013");
+
+        fn assert_lexicographic_ordering(number_str_0: &str, number_str_1: &str) {
+            assert!(number_str_0 < number_str_1, "test case should follow str ordering");
+
+            let number_0 = Lexicographic(mock_from_str::<0, 10>(number_str_0));
+            let number_1 = Lexicographic(mock_from_str::<0, 10>(number_str_1));
+            assert!(number_0 < number_1);
+            assert!(number_1 > number_0);
+        }
+    }
+
+    #[test]
+    fn by_value_ordering() {
+        assert_by_value_ordering("", "0");
+        assert_by_value_ordering("0", "00");
+        assert_by_value_ordering("1", "01");
+        assert_by_value_ordering("001", "2");
+        assert_by_value_ordering("9", "10");
+        assert_by_value_ordering("0009", "10");
+
+        fn assert_by_value_ordering(number_str_0: &str, number_str_1: &str) {
+            let number_0 = ByValue(mock_from_str::<0, 10>(number_str_0));
+            let number_1 = ByValue(mock_from_str::<0, 10>(number_str_1));
+            assert!(number_0 < number_1);
+            assert!(number_1 > number_0);
+        }
+    }
+
+    #[test]
+    fn alternative_orderings_equality() {
+        let number = mock_from_str::<0, 10>("0012");
+
+        assert_eq!(Ordering::Equal, number.cmp_lexicographic(&number));
+        assert_eq!(Ordering::Equal, number.cmp_by_value(&number));
+    }
+
+    #[test]
+    fn ordering_across_bounds() {
+        assert!(mock_from_str::<1, 3>("99") < mock_from_str::<3, 3>("000"));
+        assert!(mock_from_str::<3, 3>("001") > mock_from_str::<0, 10>("99"));
+        assert!(mock_from_str::<1, 3>("012") <= mock_from_str::<3, 3>("012"));
+    }
 }
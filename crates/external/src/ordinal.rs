@@ -0,0 +1,210 @@
+//! Ordinal encoding of padded numbers
+//!
+//! Every padded number of at most [`MAX_ORDINAL_LENGTH`] digits is assigned its
+//! rank in the crate's ordering: `""` => 0, `"0"` => 1, `"9"` => 10, `"00"` =>
+//! 11, and so on. Comparing, hashing and stepping through padded numbers then
+//! boil down to plain `u64` operations.
+
+use crate::*;
+
+/// Greatest length for which all padded numbers of that length still have an
+/// ordinal representable as an `u64`.
+pub(crate) const MAX_ORDINAL_LENGTH: u8 = 19;
+
+/// Ordinal of the first padded number with the provided length, which also is
+/// the count of all padded numbers shorter than it.
+///
+/// Valid for lengths up to and including `MAX_ORDINAL_LENGTH + 1`.
+pub(crate) const fn length_offset(length: u8) -> u64 {
+    let mut offset = 0;
+    let mut current_length = 0;
+
+    while current_length < length {
+        offset = offset * 10 + 1;
+        current_length += 1;
+    }
+
+    offset
+}
+
+pub(crate) const fn from_parts(leading_zeros: u8, number: u64) -> u64 {
+    length_offset(leading_zeros + utils::number_len(number)) + number
+}
+
+/// # Panics
+/// - If the ordinal is past the last ordinal for `MAX_ORDINAL_LENGTH`
+pub(crate) const fn into_parts(ordinal: u64) -> (u8, u64) {
+    let mut length = 0;
+    let mut offset = 0;
+
+    while length < MAX_ORDINAL_LENGTH {
+        let next_offset = offset * 10 + 1;
+
+        if next_offset > ordinal {
+            break;
+        }
+
+        length += 1;
+        offset = next_offset;
+    }
+
+    let number = ordinal - offset;
+
+    assert!(utils::number_len(number) <= length, "ordinal out of range");
+
+    (length - utils::number_len(number), number)
+}
+
+/// Ordinal range spanned by the padded numbers with a length within `MIN..=MAX`
+#[derive(Clone, Copy)]
+pub(crate) struct OrdinalBounds {
+    pub(crate) start: u64,
+    pub(crate) count: u64,
+}
+
+impl OrdinalBounds {
+    pub(crate) const fn new(min: u8, max: u8) -> Self {
+        let start = length_offset(min);
+        let count = length_offset(max + 1).saturating_sub(start);
+
+        Self { start, count }
+    }
+
+    pub(crate) const fn contains(self, ordinal: u64) -> bool {
+        ordinal >= self.start && ordinal - self.start < self.count
+    }
+
+    pub(crate) const fn end(self) -> u64 {
+        self.start + self.count - 1
+    }
+
+    /// Returns `None` only on overflow with [`OverflowStrategy::Fail`]
+    pub(crate) const fn add(self, ordinal: u64, rhs: u64, strategy: OverflowStrategy) -> Option<u64> {
+        match add_offset((ordinal - self.start) as u128, rhs, self.count as u128, strategy) {
+            Some(offset) => Some(self.start + offset as u64),
+            None => None,
+        }
+    }
+
+    /// Returns `None` only on overflow with [`OverflowStrategy::Fail`]
+    pub(crate) const fn sub(self, ordinal: u64, rhs: u64, strategy: OverflowStrategy) -> Option<u64> {
+        match sub_offset((ordinal - self.start) as u128, rhs, self.count as u128, strategy) {
+            Some(offset) => Some(self.start + offset as u64),
+            None => None,
+        }
+    }
+}
+
+/// Add to an offset within `0..count`, applying the overflow strategy when
+/// stepping past its end
+///
+/// Offsets are ordinals relative to the first padded number within some bounds,
+/// making this the arithmetic shared by all padded number types.
+pub(crate) const fn add_offset(offset: u128, rhs: u64, count: u128, strategy: OverflowStrategy) -> Option<u128> {
+    let new_offset = offset + rhs as u128;
+
+    if new_offset < count {
+        return Some(new_offset);
+    }
+
+    match strategy {
+        OverflowStrategy::Wrap => Some(new_offset % count),
+        OverflowStrategy::Saturate => Some(count - 1),
+        OverflowStrategy::Fail => None,
+    }
+}
+
+/// Subtract from an offset within `0..count`, applying the overflow strategy
+/// when stepping past its start
+pub(crate) const fn sub_offset(offset: u128, rhs: u64, count: u128, strategy: OverflowStrategy) -> Option<u128> {
+    let rhs = rhs as u128;
+
+    if rhs <= offset {
+        return Some(offset - rhs);
+    }
+
+    match strategy {
+        OverflowStrategy::Wrap => Some((offset + count - rhs % count) % count),
+        OverflowStrategy::Saturate => Some(0),
+        OverflowStrategy::Fail => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    #[test]
+    fn length_offsets() {
+        assert_eq!(0, length_offset(0));
+        assert_eq!(1, length_offset(1));
+        assert_eq!(11, length_offset(2));
+        assert_eq!(111, length_offset(3));
+        assert_eq!(11_111_111_111_111_111_111, length_offset(MAX_ORDINAL_LENGTH + 1));
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_ordinal(0, "");
+        assert_ordinal(1, "0");
+        assert_ordinal(10, "9");
+        assert_ordinal(11, "00");
+        assert_ordinal(21, "10");
+        assert_ordinal(111, "000");
+        assert_ordinal(11_111_111_111_111_111_110, "9999999999999999999");
+
+        fn assert_ordinal(expected_ordinal: u64, number_str: &str) {
+            let number = mock_from_str::<0, MAX_ORDINAL_LENGTH>(number_str);
+
+            let actual_ordinal = from_parts(number.leading_zeros, number.number);
+            assert_eq!(expected_ordinal, actual_ordinal);
+
+            assert_eq!((number.leading_zeros, number.number), into_parts(actual_ordinal));
+        }
+    }
+
+    #[test]
+    fn ordinals_follow_ordering() {
+        let mut previous = mock_from_str::<0, 4>("");
+
+        for ordinal in 1..length_offset(5) {
+            let (leading_zeros, number) = into_parts(ordinal);
+            let current = PaddedNumber::<0, 4> { leading_zeros, number };
+
+            assert!(previous < current);
+            previous = current;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ordinal_out_of_range() {
+        into_parts(length_offset(MAX_ORDINAL_LENGTH + 1));
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = OrdinalBounds::new(2, 3);
+        assert_eq!(11, bounds.start);
+        assert_eq!(1100, bounds.count);
+
+        assert!(!bounds.contains(10));
+        assert!(bounds.contains(11));
+        assert!(bounds.contains(1110));
+        assert!(!bounds.contains(1111));
+    }
+
+    #[test]
+    fn offset_arithmetic() {
+        assert_eq!(Some(4), add_offset(1, 3, 5, OverflowStrategy::Fail));
+        assert_eq!(None, add_offset(1, 4, 5, OverflowStrategy::Fail));
+        assert_eq!(Some(0), add_offset(1, 4, 5, OverflowStrategy::Wrap));
+        assert_eq!(Some(4), add_offset(1, u64::MAX, 5, OverflowStrategy::Saturate));
+
+        assert_eq!(Some(0), sub_offset(1, 1, 5, OverflowStrategy::Fail));
+        assert_eq!(None, sub_offset(1, 2, 5, OverflowStrategy::Fail));
+        assert_eq!(Some(4), sub_offset(1, 2, 5, OverflowStrategy::Wrap));
+        assert_eq!(Some(0), sub_offset(1, u64::MAX, 5, OverflowStrategy::Saturate));
+    }
+}
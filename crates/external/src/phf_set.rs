@@ -0,0 +1,86 @@
+use padded_number_internal::{phf_hashes, phf_index};
+
+use crate::*;
+
+/// Immutable set of padded numbers backed by a perfect hash function
+///
+/// Built at compile time by `include_padded_number_set!`, which finds a hash
+/// function mapping each number to a distinct slot. Membership checks are then
+/// a single hash and comparison, and may be done in const context. The numbers
+/// are also kept sorted, see [`PaddedNumberPhfSet::as_slice`].
+///
+/// ```rust
+/// # use padded_number::*;
+/// static BRANCH_NUMBERS: PaddedNumberPhfSet<3, 4> =
+///     include_padded_number_set!("tests/fixtures/branch_numbers.txt", 3, 4);
+///
+/// assert!(BRANCH_NUMBERS.contains(bound_padded_number!(3, 4, "012")));
+/// assert!(!BRANCH_NUMBERS.contains(bound_padded_number!(3, 4, "013")));
+/// ```
+#[derive(Clone, Copy)]
+pub struct PaddedNumberPhfSet<const A: u8 = 1, const B: u8 = { u8::MAX }> {
+    seed: u64,
+    displacements: &'static [(u32, u32)],
+    slots: &'static [u32],
+    numbers: &'static [PaddedNumber<A, B>],
+}
+
+impl<const A: u8, const B: u8> PaddedNumberPhfSet<A, B> {
+    #[doc(hidden)]
+    pub const fn from_raw_parts(
+        seed: u64,
+        displacements: &'static [(u32, u32)],
+        slots: &'static [u32],
+        numbers: &'static [PaddedNumber<A, B>],
+    ) -> Self {
+        Self { seed, displacements, slots, numbers }
+    }
+
+    /// Count the numbers within the set
+    pub const fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Check if the set contains no numbers
+    pub const fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// Check if the set contains the number
+    pub const fn contains(&self, padded_number: PaddedNumber<A, B>) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let hashes = phf_hashes(self.seed, padded_number.leading_zeros, padded_number.number);
+        let displacement = self.displacements[hashes.bucket as usize % self.displacements.len()];
+        let candidate = self.numbers[self.slots[phf_index(&hashes, displacement, self.len())] as usize];
+
+        candidate.leading_zeros == padded_number.leading_zeros && candidate.number == padded_number.number
+    }
+
+    /// The numbers within the set, in ascending order
+    pub const fn as_slice(&self) -> &'static [PaddedNumber<A, B>] {
+        self.numbers
+    }
+
+    /// Iterate over the numbers within the set, in ascending order
+    pub fn iter(&self) -> std::slice::Iter<'static, PaddedNumber<A, B>> {
+        self.numbers.iter()
+    }
+}
+
+impl<const A: u8, const B: u8> IntoIterator for &PaddedNumberPhfSet<A, B> {
+    type IntoIter = std::slice::Iter<'static, PaddedNumber<A, B>>;
+    type Item = &'static PaddedNumber<A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Debug for PaddedNumberPhfSet<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::*;
+
+impl<const A: u8, const B: u8> PaddedNumber<A, B> {
+    /// Check if the digits of the padded number start with those of a prefix
+    ///
+    /// Leading zeros are significant, so `"0"` and `"00"` are distinct
+    /// prefixes. An empty prefix is a prefix of every padded number.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number: PaddedNumber = padded_number!("00123");
+    /// assert!(number.starts_with(&bound_padded_number!(3, 3, "001")));
+    /// assert!(number.starts_with(&number));
+    /// assert!(!number.starts_with(&bound_padded_number!(3, 3, "000")));
+    ///
+    /// let zero: PaddedNumber = padded_number!("0");
+    /// assert!(!zero.starts_with(&bound_padded_number!(2, 2, "00")));
+    /// ```
+    pub const fn starts_with<const C: u8, const D: u8>(&self, prefix: &PaddedNumber<C, D>) -> bool {
+        if prefix.len() > self.len() {
+            return false;
+        }
+
+        let mut index = 0;
+        while index < prefix.len() {
+            if self.digit(index) != prefix.digit(index) {
+                return false;
+            }
+            index += 1;
+        }
+
+        true
+    }
+
+    /// Check if the padded number is a prefix of another, see
+    /// [`PaddedNumber::starts_with`]
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number: PaddedNumber = padded_number!("0123");
+    /// assert!(bound_padded_number!(2, 2, "01").is_prefix_of(&number));
+    /// assert!(!bound_padded_number!(1, 1, "1").is_prefix_of(&number));
+    /// ```
+    pub const fn is_prefix_of<const C: u8, const D: u8>(&self, other: &PaddedNumber<C, D>) -> bool {
+        other.starts_with(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::mock_from_str;
+
+    #[test]
+    fn starts_with() {
+        let number = mock_from_str::<0, 5>("01020");
+
+        for prefix in ["", "0", "01", "010", "0102", "01020"] {
+            assert!(number.starts_with(&mock_from_str::<0, 5>(prefix)), "{prefix}");
+        }
+
+        for prefix in ["1", "00", "011", "010200", "01021"] {
+            assert!(!number.starts_with(&mock_from_str::<0, 6>(prefix)), "{prefix}");
+        }
+    }
+
+    #[test]
+    fn is_prefix_of() {
+        let prefix = mock_from_str::<1, 3>("00");
+
+        assert!(prefix.is_prefix_of(&mock_from_str::<1, 5>("0012")));
+        assert!(!prefix.is_prefix_of(&mock_from_str::<1, 5>("0")));
+        assert!(!prefix.is_prefix_of(&mock_from_str::<1, 5>("0102")));
+    }
+}
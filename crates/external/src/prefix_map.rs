@@ -0,0 +1,357 @@
+use crate::*;
+
+/// Map from padded numbers to values, supporting longest-prefix matching
+///
+/// Stored as a trie with one level per digit, leading zeros included. Keys
+/// `"0"` and `"00"` are therefore distinct, and `"0"` is a prefix of `"00"`.
+/// Queried numbers may have other length bounds than the keys, e.g. when
+/// looking up a full phone number among its routing prefixes.
+///
+/// Iteration yields keys by their digits in lexicographic order, see
+/// [`Lexicographic`].
+///
+/// ```rust
+/// # use padded_number::*;
+/// let mut routes = PaddedPrefixMap::<&str>::new();
+/// routes.insert(padded_number!("0"), "national");
+/// routes.insert(padded_number!("00"), "international");
+/// routes.insert(padded_number!("0046"), "sweden");
+///
+/// let number: PaddedNumber = padded_number!("0046701234567");
+/// let (prefix, route) = routes.longest_prefix_match(&number).unwrap();
+/// assert_eq!(prefix, "0046");
+/// assert_eq!(route, &"sweden");
+///
+/// let number: PaddedNumber = padded_number!("0701234567");
+/// assert_eq!(
+///     Some(&"national"),
+///     routes.longest_prefix_match(&number).map(|(_, route)| route)
+/// );
+///
+/// let below = routes
+///     .iter_prefix(&bound_padded_number!(2, 2, "00"))
+///     .map(|(_, route)| *route)
+///     .collect::<Vec<_>>();
+/// assert_eq!(vec!["international", "sweden"], below);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct PaddedPrefixMap<V, const A: u8 = 1, const B: u8 = { u8::MAX }> {
+    root: Node<V>,
+    len: usize,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Node<V> {
+    value: Option<V>,
+    children: [Option<Box<Node<V>>>; 10],
+}
+
+impl<V> Node<V> {
+    const NO_CHILD: Option<Box<Self>> = None;
+
+    const fn new() -> Self {
+        Self { value: None, children: [Self::NO_CHILD; 10] }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.iter().all(Option::is_none)
+    }
+
+    fn get<const A: u8, const B: u8>(&self, key: &PaddedNumber<A, B>) -> Option<&Self> {
+        digits(key).try_fold(self, |node, digit| node.children[digit as usize].as_deref())
+    }
+
+    fn get_mut<const A: u8, const B: u8>(&mut self, key: &PaddedNumber<A, B>) -> Option<&mut Self> {
+        digits(key).try_fold(self, |node, digit| node.children[digit as usize].as_deref_mut())
+    }
+
+    /// Removes the value at the remaining digits, pruning emptied nodes
+    fn remove(&mut self, mut digits: impl Iterator<Item = u8>) -> Option<V> {
+        let Some(digit) = digits.next() else {
+            return self.value.take();
+        };
+
+        let child = self.children[digit as usize].as_deref_mut()?;
+        let value = child.remove(digits);
+
+        if child.is_empty() {
+            self.children[digit as usize] = None;
+        }
+
+        value
+    }
+}
+
+impl<V, const A: u8, const B: u8> PaddedPrefixMap<V, A, B> {
+    /// Create an empty [`PaddedPrefixMap`]
+    pub const fn new() -> Self {
+        Self { root: Node::new(), len: 0 }
+    }
+
+    /// Number of entries in the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the map contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert a value, returning the previous one for the same key
+    pub fn insert(&mut self, key: PaddedNumber<A, B>, value: V) -> Option<V> {
+        let node = digits(&key).fold(&mut self.root, |node, digit| {
+            node.children[digit as usize].get_or_insert_with(|| Box::new(Node::new()))
+        });
+
+        let previous = node.value.replace(value);
+
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// Remove the value stored for exactly the key
+    pub fn remove(&mut self, key: &PaddedNumber<A, B>) -> Option<V> {
+        let value = self.root.remove(digits(key));
+
+        if value.is_some() {
+            self.len -= 1;
+        }
+
+        value
+    }
+
+    /// Get the value stored for exactly the key
+    pub fn get(&self, key: &PaddedNumber<A, B>) -> Option<&V> {
+        self.root.get(key)?.value.as_ref()
+    }
+
+    /// Get a mutable reference to the value stored for exactly the key
+    pub fn get_mut(&mut self, key: &PaddedNumber<A, B>) -> Option<&mut V> {
+        self.root.get_mut(key)?.value.as_mut()
+    }
+
+    /// Check if a value is stored for exactly the key
+    pub fn contains_key(&self, key: &PaddedNumber<A, B>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Find the longest key which is a prefix of the padded number
+    pub fn longest_prefix_match<const C: u8, const D: u8>(
+        &self,
+        padded_number: &PaddedNumber<C, D>,
+    ) -> Option<(PaddedNumber<A, B>, &V)> {
+        let mut node = &self.root;
+        let mut longest_match = node.value.as_ref().map(|value| (0, value));
+
+        for (index, digit) in digits(padded_number).enumerate() {
+            let Some(child) = node.children[digit as usize].as_deref() else {
+                break;
+            };
+
+            node = child;
+
+            if let Some(value) = &node.value {
+                longest_match = Some((index + 1, value));
+            }
+        }
+
+        longest_match.map(|(len, value)| (from_digits(digits(padded_number).take(len)), value))
+    }
+
+    /// Iterate over all entries
+    pub fn iter(&self) -> PrefixIter<'_, V, A, B> {
+        PrefixIter::new(Some(&self.root), Vec::new())
+    }
+
+    /// Iterate over all entries whose keys start with the prefix, including
+    /// the prefix itself
+    pub fn iter_prefix<const C: u8, const D: u8>(&self, prefix: &PaddedNumber<C, D>) -> PrefixIter<'_, V, A, B> {
+        PrefixIter::new(self.root.get(prefix), digits(prefix).collect())
+    }
+}
+
+impl<V, const A: u8, const B: u8> Default for PaddedPrefixMap<V, A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, const A: u8, const B: u8> FromIterator<(PaddedNumber<A, B>, V)> for PaddedPrefixMap<V, A, B> {
+    fn from_iter<I: IntoIterator<Item = (PaddedNumber<A, B>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V, const A: u8, const B: u8> Extend<(PaddedNumber<A, B>, V)> for PaddedPrefixMap<V, A, B> {
+    fn extend<I: IntoIterator<Item = (PaddedNumber<A, B>, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<V: std::fmt::Debug, const A: u8, const B: u8> std::fmt::Debug for PaddedPrefixMap<V, A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, V, const A: u8, const B: u8> IntoIterator for &'a PaddedPrefixMap<V, A, B> {
+    type IntoIter = PrefixIter<'a, V, A, B>;
+    type Item = (PaddedNumber<A, B>, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`PaddedPrefixMap`]
+pub struct PrefixIter<'a, V, const A: u8, const B: u8> {
+    // node, key length of its parent, digit leading to it
+    stack: Vec<(&'a Node<V>, usize, Option<u8>)>,
+    key_digits: Vec<u8>,
+}
+
+impl<'a, V, const A: u8, const B: u8> PrefixIter<'a, V, A, B> {
+    fn new(node: Option<&'a Node<V>>, key_digits: Vec<u8>) -> Self {
+        let stack = node.map(|node| (node, key_digits.len(), None)).into_iter().collect();
+        Self { stack, key_digits }
+    }
+}
+
+impl<'a, V, const A: u8, const B: u8> Iterator for PrefixIter<'a, V, A, B> {
+    type Item = (PaddedNumber<A, B>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, parent_len, digit) = self.stack.pop()?;
+
+            self.key_digits.truncate(parent_len);
+            self.key_digits.extend(digit);
+
+            let key_len = self.key_digits.len();
+            for (digit, child) in node.children.iter().enumerate().rev() {
+                if let Some(child) = child {
+                    self.stack.push((child, key_len, Some(digit as u8)));
+                }
+            }
+
+            if let Some(value) = &node.value {
+                return Some((from_digits(self.key_digits.iter().copied()), value));
+            }
+        }
+    }
+}
+
+fn digits<const A: u8, const B: u8>(padded_number: &PaddedNumber<A, B>) -> impl Iterator<Item = u8> + '_ {
+    (0..padded_number.len()).map(|index| padded_number.digit(index))
+}
+
+/// Digits must be those of a valid `PaddedNumber<A, B>`
+fn from_digits<const A: u8, const B: u8>(digits: impl Iterator<Item = u8>) -> PaddedNumber<A, B> {
+    let (leading_zeros, number) = digits.fold((0, 0), |(leading_zeros, number): (u8, u64), digit| {
+        match number == 0 && digit == 0 {
+            true => (leading_zeros + 1, number),
+            false => (leading_zeros, number * 10 + digit as u64),
+        }
+    });
+
+    PaddedNumber { leading_zeros, number }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    fn map(keys: &[&str]) -> PaddedPrefixMap<String, 0, 5> {
+        keys.iter().map(|key| (mock_from_str(key), key.to_string())).collect()
+    }
+
+    fn longest_match(map: &PaddedPrefixMap<String, 0, 5>, str: &str) -> Option<String> {
+        map.longest_prefix_match(&mock_from_str::<0, 10>(str))
+            .map(|(key, value)| {
+                assert_eq!(&key.to_string(), value);
+                key.to_string()
+            })
+    }
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = map(&["0", "00", "1"]);
+        assert_eq!(3, map.len());
+
+        assert_eq!(Some("00".to_string()), map.insert(mock_from_str("00"), "y".to_string()));
+        assert_eq!(3, map.len());
+        assert_eq!(Some(&"y".to_string()), map.get(&mock_from_str("00")));
+        assert_eq!(None, map.get(&mock_from_str("000")));
+
+        assert_eq!(Some("0".to_string()), map.remove(&mock_from_str("0")));
+        assert_eq!(None, map.remove(&mock_from_str("0")));
+        assert!(map.contains_key(&mock_from_str("00")));
+        assert_eq!(2, map.len());
+
+        map.get_mut(&mock_from_str("1")).unwrap().push('!');
+        assert_eq!(Some(&"1!".to_string()), map.get(&mock_from_str("1")));
+    }
+
+    #[test]
+    fn remove_prunes_nodes() {
+        let mut map = map(&["0123"]);
+        map.remove(&mock_from_str("0123"));
+
+        assert!(map.is_empty());
+        assert_eq!(PaddedPrefixMap::new(), map);
+    }
+
+    #[test]
+    fn longest_prefix_match() {
+        let map = map(&["0", "00", "0046", "1"]);
+
+        assert_eq!(Some("0046".to_string()), longest_match(&map, "004670"));
+        assert_eq!(Some("00".to_string()), longest_match(&map, "0047"));
+        assert_eq!(Some("00".to_string()), longest_match(&map, "00"));
+        assert_eq!(Some("0".to_string()), longest_match(&map, "01"));
+        assert_eq!(None, longest_match(&map, "2"));
+        assert_eq!(None, longest_match(&map, ""));
+    }
+
+    #[test]
+    fn empty_key_matches_everything() {
+        let map = map(&["", "9"]);
+
+        assert_eq!(Some("".to_string()), longest_match(&map, "0"));
+        assert_eq!(Some("9".to_string()), longest_match(&map, "90"));
+    }
+
+    #[test]
+    fn iteration_is_lexicographic() {
+        let map = map(&["1", "10", "0", "00", "0009", "01", "2"]);
+
+        let keys = map.iter().map(|(key, _)| key.to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["0", "00", "0009", "01", "1", "10", "2"], keys);
+    }
+
+    #[test]
+    fn iter_prefix() {
+        let map = map(&["0", "00", "0009", "01", "1"]);
+
+        let keys = |prefix: &str| {
+            map.iter_prefix(&mock_from_str::<0, 5>(prefix))
+                .map(|(key, _)| key.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["00", "0009"], keys("00"));
+        assert_eq!(vec!["0", "00", "0009", "01"], keys("0"));
+        assert_eq!(vec!["0009"], keys("000"));
+        assert!(keys("2").is_empty());
+        assert_eq!(5, keys("").len());
+    }
+}
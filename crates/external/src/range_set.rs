@@ -0,0 +1,347 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::*;
+
+/// Set of padded numbers stored as non-overlapping inclusive ranges
+///
+/// Ranges follow the crate's ordering, and are stored by the ordinals of their
+/// bounds, see [`CompactPaddedNumber::ordinal`]. Neighbouring ranges are
+/// therefore merged even when they are of different lengths, `"9"` being
+/// directly followed by `"00"`.
+///
+/// ```rust
+/// # use padded_number::*;
+/// let mut set = PaddedRangeSet::<1, 3>::new();
+/// set.insert(bound_padded_number!(1, 3, "5")..=bound_padded_number!(1, 3, "9"));
+/// set.insert(bound_padded_number!(1, 3, "00")..=bound_padded_number!(1, 3, "04"));
+///
+/// assert_eq!(1, set.ranges().count());
+/// assert_eq!(10, set.count());
+/// assert!(set.contains(bound_padded_number!(1, 3, "00")));
+/// ```
+///
+/// Like with [`CompactPaddedNumber`], the upper length bound may not exceed
+/// 19.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct PaddedRangeSet<const A: u8 = 1, const B: u8 = 19> {
+    // start ordinal => end ordinal, both inclusive
+    ranges: BTreeMap<u64, u64>,
+}
+
+impl<const A: u8, const B: u8> PaddedRangeSet<A, B> {
+    /// Create an empty [`PaddedRangeSet`]
+    pub const fn new() -> Self {
+        Self { ranges: BTreeMap::new() }
+    }
+
+    /// Create a [`PaddedRangeSet`] containing every padded number within the
+    /// length bounds
+    pub fn full() -> Self {
+        let bounds = CompactPaddedNumber::<A, B>::BOUNDS;
+
+        let mut set = Self::new();
+        if bounds.count > 0 {
+            set.ranges.insert(bounds.start, bounds.end());
+        }
+        set
+    }
+
+    /// Check if the set contains no numbers
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Count the numbers within the set
+    pub fn count(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
+    }
+
+    /// Check if the set contains the number
+    pub fn contains(&self, padded_number: PaddedNumber<A, B>) -> bool {
+        let ordinal = encode(padded_number);
+
+        self.ranges
+            .range(..=ordinal)
+            .next_back()
+            .is_some_and(|(_, end)| ordinal <= *end)
+    }
+
+    /// Insert a range of numbers, merging it with any overlapping or
+    /// neighbouring ranges
+    ///
+    /// Empty ranges, those starting after their end, are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<PaddedNumber<A, B>>) {
+        if let Some((start, end)) = decompose(range) {
+            self.insert_ordinals(start, end);
+        }
+    }
+
+    /// Remove a range of numbers, splitting any range it lies within
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let mut set = PaddedRangeSet::<2, 2>::full();
+    /// set.remove(bound_padded_number!(2, 2, "10")..=bound_padded_number!(2, 2, "89"));
+    ///
+    /// assert_eq!(2, set.ranges().count());
+    /// assert_eq!(20, set.count());
+    /// ```
+    pub fn remove(&mut self, range: RangeInclusive<PaddedNumber<A, B>>) {
+        if let Some((start, end)) = decompose(range) {
+            self.remove_ordinals(start, end);
+        }
+    }
+
+    /// Numbers within either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+
+        for (&start, &end) in &other.ranges {
+            union.insert_ordinals(start, end);
+        }
+
+        union
+    }
+
+    /// Numbers within this set, but not within the other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+
+        for (&start, &end) in &other.ranges {
+            difference.remove_ordinals(start, end);
+        }
+
+        difference
+    }
+
+    /// Numbers within both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.difference(&other.complement())
+    }
+
+    /// Numbers within the length bounds, but not within the set
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let mut set = PaddedRangeSet::<1, 2>::new();
+    /// set.insert(bound_padded_number!(1, 2, "1")..=bound_padded_number!(1, 2, "99"));
+    ///
+    /// let complement = set.complement();
+    /// assert!(complement.contains(bound_padded_number!(1, 2, "0")));
+    /// assert_eq!(1, complement.count());
+    /// ```
+    pub fn complement(&self) -> Self {
+        Self::full().difference(self)
+    }
+
+    /// Iterate over the ranges of the set, in ascending order
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<PaddedNumber<A, B>>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| decode(start)..=decode(end))
+    }
+
+    /// Iterate over the numbers of the set, in ascending order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = PaddedNumber<A, B>> + '_ {
+        self.ranges.iter().flat_map(|(&start, &end)| (start..=end).map(decode))
+    }
+
+    fn insert_ordinals(&mut self, mut start: u64, mut end: u64) {
+        // preceding range which overlaps or neighbours the new one
+        if let Some((&previous_start, &previous_end)) = self.ranges.range(..=start).next_back()
+            && previous_end + 1 >= start
+        {
+            start = previous_start;
+            end = end.max(previous_end);
+        }
+
+        // succeeding ranges which overlap or neighbour the new one
+        let merged_starts = self
+            .ranges
+            .range(start..=end + 1)
+            .map(|(&merged_start, &merged_end)| {
+                end = end.max(merged_end);
+                merged_start
+            })
+            .collect::<Vec<_>>();
+
+        for merged_start in merged_starts {
+            self.ranges.remove(&merged_start);
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    fn remove_ordinals(&mut self, start: u64, end: u64) {
+        let overlapping = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|(_, overlapping_end)| **overlapping_end >= start)
+            .map(|(&overlapping_start, &overlapping_end)| (overlapping_start, overlapping_end))
+            .collect::<Vec<_>>();
+
+        for (overlapping_start, overlapping_end) in overlapping {
+            self.ranges.remove(&overlapping_start);
+
+            if overlapping_start < start {
+                self.ranges.insert(overlapping_start, start - 1);
+            }
+
+            if overlapping_end > end {
+                self.ranges.insert(end + 1, overlapping_end);
+            }
+        }
+    }
+}
+
+impl<const A: u8, const B: u8> FromIterator<RangeInclusive<PaddedNumber<A, B>>> for PaddedRangeSet<A, B> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<PaddedNumber<A, B>>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const A: u8, const B: u8> Extend<RangeInclusive<PaddedNumber<A, B>>> for PaddedRangeSet<A, B> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<PaddedNumber<A, B>>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<const A: u8, const B: u8> std::fmt::Debug for PaddedRangeSet<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.ranges()).finish()
+    }
+}
+
+fn encode<const A: u8, const B: u8>(padded_number: PaddedNumber<A, B>) -> u64 {
+    CompactPaddedNumber::from_padded_number(padded_number).ordinal()
+}
+
+fn decode<const A: u8, const B: u8>(ordinal: u64) -> PaddedNumber<A, B> {
+    CompactPaddedNumber::<A, B>::from_ordinal_unchecked(ordinal).into_padded_number()
+}
+
+fn decompose<const A: u8, const B: u8>(range: RangeInclusive<PaddedNumber<A, B>>) -> Option<(u64, u64)> {
+    let (start, end) = range.into_inner();
+    let (start, end) = (encode(start), encode(end));
+
+    (start <= end).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    fn range(start: &str, end: &str) -> RangeInclusive<PaddedNumber<0, 3>> {
+        mock_from_str(start)..=mock_from_str(end)
+    }
+
+    fn set(ranges: &[(&str, &str)]) -> PaddedRangeSet<0, 3> {
+        ranges.iter().map(|(start, end)| range(start, end)).collect()
+    }
+
+    fn assert_ranges(expected: &[(&str, &str)], set: &PaddedRangeSet<0, 3>) {
+        let actual = set
+            .ranges()
+            .map(|range| (range.start().to_string(), range.end().to_string()))
+            .collect::<Vec<_>>();
+
+        let expected = expected
+            .iter()
+            .map(|(start, end)| (start.to_string(), end.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn insert_merges_across_lengths() {
+        assert_ranges(&[("5", "04")], &set(&[("5", "9"), ("00", "04")]));
+        assert_ranges(&[("", "0")], &set(&[("0", "0"), ("", "")]));
+        assert_ranges(&[("98", "001")], &set(&[("000", "001"), ("98", "99")]));
+    }
+
+    #[test]
+    fn insert_merges_overlapping() {
+        assert_ranges(&[("1", "9")], &set(&[("1", "3"), ("7", "9"), ("2", "8")]));
+        assert_ranges(&[("1", "9")], &set(&[("1", "9"), ("3", "4")]));
+        assert_ranges(&[("1", "3"), ("5", "6")], &set(&[("5", "6"), ("1", "3")]));
+    }
+
+    #[test]
+    fn insert_ignores_empty_range() {
+        assert!(set(&[("9", "1")]).is_empty());
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = set(&[("0", "99")]);
+        set.remove(range("9", "00"));
+        assert_ranges(&[("0", "8"), ("01", "99")], &set);
+
+        set.remove(range("5", "05"));
+        assert_ranges(&[("0", "4"), ("06", "99")], &set);
+
+        set.remove(range("", "999"));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        let set = set(&[("9", "00"), ("05", "05")]);
+
+        assert!(!set.contains(mock_from_str("8")));
+        assert!(set.contains(mock_from_str("9")));
+        assert!(set.contains(mock_from_str("00")));
+        assert!(!set.contains(mock_from_str("01")));
+        assert!(set.contains(mock_from_str("05")));
+        assert!(!set.contains(mock_from_str("5")));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[("0", "5"), ("00", "09")]);
+        let b = set(&[("3", "01"), ("08", "10")]);
+
+        assert_ranges(&[("0", "10")], &a.union(&b));
+        assert_ranges(&[("3", "5"), ("00", "01"), ("08", "09")], &a.intersection(&b));
+        assert_ranges(&[("0", "2"), ("02", "07")], &a.difference(&b));
+        assert_ranges(&[("6", "9"), ("10", "10")], &b.difference(&a));
+    }
+
+    #[test]
+    fn complement() {
+        let set = set(&[("0", "9"), ("500", "999")]);
+        assert_ranges(&[("", ""), ("00", "499")], &set.complement());
+
+        assert_eq!(PaddedRangeSet::full(), PaddedRangeSet::<0, 3>::new().complement());
+        assert!(PaddedRangeSet::<0, 3>::full().complement().is_empty());
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(0, PaddedRangeSet::<0, 3>::new().count());
+        assert_eq!(1111, PaddedRangeSet::<0, 3>::full().count());
+        assert_eq!(8, set(&[("7", "02"), ("998", "999")]).count());
+    }
+
+    #[test]
+    fn iteration() {
+        let set = set(&[("8", "01"), ("5", "5")]);
+
+        let values = set.iter().map(|number| number.to_string()).collect::<Vec<_>>();
+        assert_eq!(["5", "8", "9", "00", "01"], values.as_slice());
+
+        assert_eq!(Some(mock_from_str("01")), set.iter().next_back());
+    }
+
+    #[test]
+    fn full_range_of_max_length() {
+        let set = PaddedRangeSet::<0, 19>::full();
+        assert_eq!(11_111_111_111_111_111_111, set.count());
+        assert!(set.contains(mock_from_str("9999999999999999999")));
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{arithmetic::ValueRange, *};
+
+/// Padded number bounded by its numeric value in addition to its length
+///
+/// Holds the padded numbers within the `A..=B` length bounds whose numeric
+/// value lies within `LOW..=HIGH`, both inclusive. Identifiers such as "0001
+/// to 5000, always 4 digits" may therefore be declared as
+/// `RangedPaddedNumber<1, 5000, 4, 4>`.
+///
+/// ```rust
+/// # use padded_number::*;
+/// type BranchNumber = RangedPaddedNumber<1, 5000, 4, 4>;
+///
+/// assert!(BranchNumber::try_new("0042").is_ok());
+/// assert!(BranchNumber::try_new("0000").is_err());
+/// assert!(BranchNumber::try_new("5001").is_err());
+///
+/// // wraps past the end of the value range
+/// let last = BranchNumber::try_new("5000").unwrap();
+/// assert_eq!("0001", (last + 1).to_string());
+/// ```
+///
+/// Arithmetic and iteration follow the ordering of [`PaddedNumber`],
+/// skipping any number outside of the value range. The value range applies to
+/// each length within the length bounds, so `"9"` is followed by `"05"` for a
+/// `RangedPaddedNumber<5, 20, 1, 2>`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangedPaddedNumber<const LOW: u64, const HIGH: u64, const A: u8 = 1, const B: u8 = { u8::MAX }> {
+    padded_number: PaddedNumber<A, B>,
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> RangedPaddedNumber<LOW, HIGH, A, B> {
+    /// Count of the padded numbers within both the length bounds and the
+    /// value range
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// assert_eq!(5000, RangedPaddedNumber::<1, 5000, 4, 4>::COUNT);
+    /// assert_eq!(5 + 16, RangedPaddedNumber::<5, 20, 1, 2>::COUNT);
+    /// ```
+    pub const COUNT: u128 = PaddedNumber::<A, B>::bounds_count(Self::RANGE);
+    const RANGE: ValueRange = ValueRange { low: LOW, high: HIGH };
+
+    /// Create a new [`RangedPaddedNumber`]
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// assert_eq!(
+    ///     Err(ParseRangedPaddedNumberError::OutOfRange(1, 5000, 5001)),
+    ///     RangedPaddedNumber::<1, 5000, 4, 4>::try_new("5001")
+    /// );
+    /// ```
+    pub const fn try_new(str: &str) -> Result<Self, ParseRangedPaddedNumberError> {
+        match PaddedNumber::try_new(str) {
+            Ok(padded_number) => match Self::from_padded_number(padded_number) {
+                Some(ranged) => Ok(ranged),
+                None => Err(ParseRangedPaddedNumberError::OutOfRange(
+                    LOW,
+                    HIGH,
+                    padded_number.number,
+                )),
+            },
+            Err(err) => Err(ParseRangedPaddedNumberError::InvalidPaddedNumber(err)),
+        }
+    }
+
+    /// Wrap a [`PaddedNumber`] with the same length bounds, returning
+    /// `None` if its value lies outside of the value range
+    pub const fn from_padded_number(padded_number: PaddedNumber<A, B>) -> Option<Self> {
+        match Self::RANGE.contains(padded_number.number) {
+            true => Some(Self { padded_number }),
+            false => None,
+        }
+    }
+
+    /// Unwrap into a [`PaddedNumber`] with the same length bounds
+    pub const fn into_padded_number(self) -> PaddedNumber<A, B> {
+        self.padded_number
+    }
+
+    /// Calculate the length of the padded number, including any leading zeros
+    pub const fn len(&self) -> u8 {
+        self.padded_number.len()
+    }
+
+    /// Check if the number if empty, e.g. if and only if it is `""`.
+    pub const fn is_empty(&self) -> bool {
+        self.padded_number.is_empty()
+    }
+
+    /// Wrapping addition with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::wrapping_add`] but within the value range, and
+    /// used within the `impl Add<u64> for RangedPaddedNumber` implementation.
+    pub const fn wrapping_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Wrap)
+            .expect("wrapping never fails")
+    }
+
+    /// Saturating addition with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::saturating_add`] but within the value range.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let number = RangedPaddedNumber::<1, 5000, 4, 4>::try_new("4990").unwrap();
+    /// assert_eq!("5000", number.saturating_add(100).to_string());
+    /// ```
+    pub const fn saturating_add(self, rhs: u64) -> Self {
+        self.add_impl(rhs, OverflowStrategy::Saturate)
+            .expect("saturating never fails")
+    }
+
+    /// Checked addition with u64 as right-hand side
+    ///
+    /// Returns `None` instead of wrapping past the end of the value range.
+    pub const fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.add_impl(rhs, OverflowStrategy::Fail)
+    }
+
+    /// Wrapping subtraction with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::wrapping_sub`] but within the value range, and
+    /// used within the `impl Sub<u64> for RangedPaddedNumber` implementation.
+    pub const fn wrapping_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Wrap)
+            .expect("wrapping never fails")
+    }
+
+    /// Saturating subtraction with u64 as right-hand side
+    ///
+    /// Same as [`PaddedNumber::saturating_sub`] but within the value range.
+    pub const fn saturating_sub(self, rhs: u64) -> Self {
+        self.sub_impl(rhs, OverflowStrategy::Saturate)
+            .expect("saturating never fails")
+    }
+
+    /// Checked subtraction with u64 as right-hand side
+    ///
+    /// Returns `None` instead of wrapping past the start of the value range.
+    pub const fn checked_sub(self, rhs: u64) -> Option<Self> {
+        self.sub_impl(rhs, OverflowStrategy::Fail)
+    }
+
+    /// Iterate over all padded numbers within the length bounds and value
+    /// range, in ascending order
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// let numbers = RangedPaddedNumber::<8, 11, 1, 2>::iter()
+    ///     .map(|number| number.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec!["8", "9", "08", "09", "10", "11"], numbers);
+    /// ```
+    pub fn iter() -> RangedIter<LOW, HIGH, A, B> {
+        RangedIter { front: 0, back: Self::COUNT }
+    }
+
+    /// Iterate over the padded numbers from this one and onwards, in
+    /// ascending order
+    pub fn iter_from(self) -> RangedIter<LOW, HIGH, A, B> {
+        RangedIter { front: self.padded_number.offset(Self::RANGE), back: Self::COUNT }
+    }
+
+    const fn add_impl(self, rhs: u64, strategy: OverflowStrategy) -> Option<Self> {
+        match self.padded_number.add_impl(rhs, strategy, Self::RANGE, Self::COUNT) {
+            Some(padded_number) => Some(Self::from_padded_number_unchecked(padded_number)),
+            None => None,
+        }
+    }
+
+    const fn sub_impl(self, rhs: u64, strategy: OverflowStrategy) -> Option<Self> {
+        match self.padded_number.sub_impl(rhs, strategy, Self::RANGE, Self::COUNT) {
+            Some(padded_number) => Some(Self::from_padded_number_unchecked(padded_number)),
+            None => None,
+        }
+    }
+
+    const fn from_offset(offset: u128) -> Self {
+        Self::from_padded_number_unchecked(PaddedNumber::from_offset(offset, Self::RANGE))
+    }
+
+    const fn from_padded_number_unchecked(padded_number: PaddedNumber<A, B>) -> Self {
+        Self { padded_number }
+    }
+}
+
+/// Iterator over [`RangedPaddedNumber`]s in ascending order, see
+/// [`RangedPaddedNumber::iter`]
+#[derive(Debug, Clone)]
+pub struct RangedIter<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> {
+    front: u128,
+    back: u128,
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> Iterator for RangedIter<LOW, HIGH, A, B> {
+    type Item = RangedPaddedNumber<LOW, HIGH, A, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let number = RangedPaddedNumber::from_offset(self.front);
+        self.front += 1;
+
+        Some(number)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.back - self.front).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> DoubleEndedIterator for RangedIter<LOW, HIGH, A, B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(RangedPaddedNumber::from_offset(self.back))
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> From<RangedPaddedNumber<LOW, HIGH, A, B>>
+    for PaddedNumber<A, B>
+{
+    fn from(ranged: RangedPaddedNumber<LOW, HIGH, A, B>) -> Self {
+        ranged.into_padded_number()
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> FromStr for RangedPaddedNumber<LOW, HIGH, A, B> {
+    type Err = ParseRangedPaddedNumberError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::try_new(str)
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> std::fmt::Debug
+    for RangedPaddedNumber<LOW, HIGH, A, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.padded_number, f)
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> std::fmt::Display
+    for RangedPaddedNumber<LOW, HIGH, A, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.padded_number, f)
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> Add<u64> for RangedPaddedNumber<LOW, HIGH, A, B> {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self::wrapping_add(self, rhs)
+    }
+}
+
+impl<const LOW: u64, const HIGH: u64, const A: u8, const B: u8> Sub<u64> for RangedPaddedNumber<LOW, HIGH, A, B> {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self::wrapping_sub(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type BranchNumber = RangedPaddedNumber<1, 5000, 4, 4>;
+    type Variable = RangedPaddedNumber<5, 20, 1, 2>;
+
+    fn branch_number(str: &str) -> BranchNumber {
+        str.parse().unwrap()
+    }
+
+    fn variable(str: &str) -> Variable {
+        str.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("0001", branch_number("0001").to_string());
+        assert_eq!("5000", branch_number("5000").to_string());
+
+        assert_eq!(
+            Err(ParseRangedPaddedNumberError::OutOfRange(1, 5000, 0)),
+            BranchNumber::try_new("0000")
+        );
+        assert_eq!(
+            Err(ParseRangedPaddedNumberError::InvalidPaddedNumber(
+                ParsePaddedNumberError::TooShort(4, 3)
+            )),
+            BranchNumber::try_new("001")
+        );
+        assert!(Variable::try_new("04").is_err());
+        assert!(Variable::try_new("21").is_err());
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        assert_eq!(branch_number("0001"), branch_number("5000") + 1);
+        assert_eq!(branch_number("5000"), branch_number("0001") - 1);
+        assert_eq!(branch_number("0002"), branch_number("4999") + 3);
+        assert_eq!(branch_number("0042"), branch_number("0042") + 5000);
+
+        assert_eq!(variable("05"), variable("9") + 1);
+        assert_eq!(variable("5"), variable("20") + 1);
+        assert_eq!(variable("9"), variable("05") - 1);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(branch_number("5000"), branch_number("4990").saturating_add(u64::MAX));
+        assert_eq!(branch_number("0001"), branch_number("0010").saturating_sub(u64::MAX));
+        assert_eq!(variable("20"), variable("5").saturating_add(100));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(Some(branch_number("5000")), branch_number("0001").checked_add(4999));
+        assert_eq!(None, branch_number("0001").checked_add(5000));
+        assert_eq!(Some(branch_number("0001")), branch_number("5000").checked_sub(4999));
+        assert_eq!(None, branch_number("5000").checked_sub(5000));
+    }
+
+    #[test]
+    fn iteration() {
+        assert_eq!(BranchNumber::COUNT, BranchNumber::iter().count() as u128);
+        assert_eq!(Some(branch_number("0001")), BranchNumber::iter().next());
+        assert_eq!(Some(branch_number("5000")), BranchNumber::iter().next_back());
+
+        let numbers = branch_number("4998")
+            .iter_from()
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["4998", "4999", "5000"], numbers);
+
+        let numbers = Variable::iter().map(|number| number.to_string()).collect::<Vec<_>>();
+        assert_eq!(["5", "6", "7", "8", "9", "05"], numbers[..6]);
+        assert_eq!(Some("20"), numbers.last().map(String::as_str));
+        assert!(Variable::iter().is_sorted());
+    }
+
+    #[test]
+    fn many_lengths() {
+        type Ticket = RangedPaddedNumber<1, 5000>;
+
+        assert_eq!(9 + 99 + 999 + 252 * 5000, Ticket::COUNT);
+
+        let last = Ticket::iter().next_back().unwrap();
+        assert_eq!(255, last.len());
+        assert_eq!(Ticket::try_new("1").unwrap(), last + 1);
+        assert_eq!(last, Ticket::try_new("1").unwrap() - 1);
+    }
+
+    #[test]
+    fn empty_value_range() {
+        assert_eq!(0, RangedPaddedNumber::<100, 200, 1, 2>::COUNT);
+        assert_eq!(None, RangedPaddedNumber::<100, 200, 1, 2>::iter().next());
+        assert!(RangedPaddedNumber::<100, 200, 1, 2>::try_new("99").is_err());
+    }
+}
@@ -9,8 +9,8 @@ use crate::*;
 /// #![feature(generic_const_exprs)]
 ///
 /// # use padded_number_macros::*;
-/// # use crate::padded_number::ResizePaddedNumber;
-/// let a = bound_padded_number!(2, 3, "123").resize();
+/// # use crate::padded_number::{PaddedNumber, ResizePaddedNumber};
+/// let a: PaddedNumber<1, 5> = bound_padded_number!(2, 3, "123").resize();
 /// let b = bound_padded_number!(1, 5, "123");
 /// assert_eq!(a, b)
 /// ```
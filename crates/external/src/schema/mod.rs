@@ -0,0 +1,55 @@
+//! Schema generation
+//!
+//! Schemas describe the human-readable serde representation, a string of
+//! digits constrained by the length bounds.
+//!
+//! No padded number fits within length bounds where `A > B`, and generating a
+//! schema for them is therefore a compile time error rather than an
+//! unsatisfiable pattern.
+//!
+//! ```compile_fail
+//! # use padded_number::*;
+//! let schema = schemars::schema_for!(PaddedNumber<3, 1>);
+//! ```
+
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(test)]
+mod schemars_tests;
+
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(test)]
+mod utoipa_tests;
+
+/// Name including the length bounds, as schemas of differently bound padded
+/// numbers may not share a name.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+fn schema_name<const A: u8, const B: u8>(type_name: &str) -> String {
+    format!("{type_name}_{A}_{B}")
+}
+
+/// An empty string is matched if `A == 0`
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+fn pattern<const A: u8, const B: u8>() -> String {
+    let (min, max) = SchemaBounds::<A, B>::BOUNDS;
+
+    match min == max {
+        true => format!("^[0-9]{{{min}}}$"),
+        false => format!("^[0-9]{{{min},{max}}}$"),
+    }
+}
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+struct SchemaBounds<const A: u8, const B: u8>;
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+impl<const A: u8, const B: u8> SchemaBounds<A, B> {
+    const BOUNDS: (u8, u8) = {
+        assert!(
+            A <= B,
+            "min length bound may not exceed the max length bound when generating a schema"
+        );
+        (A, B)
+    };
+}
@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::*;
+
+impl<const A: u8, const B: u8> JsonSchema for PaddedNumber<A, B> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        super::schema_name::<A, B>("PaddedNumber").into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("{}::{}", module_path!(), Self::schema_name()).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_schema::<A, B>()
+    }
+}
+
+impl<const A: u8, const B: u8> JsonSchema for CompactPaddedNumber<A, B> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        super::schema_name::<A, B>("CompactPaddedNumber").into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("{}::{}", module_path!(), Self::schema_name()).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_schema::<A, B>()
+    }
+}
+
+fn string_schema<const A: u8, const B: u8>() -> Schema {
+    json_schema!({
+        "type": "string",
+        "pattern": super::pattern::<A, B>(),
+        "minLength": A,
+        "maxLength": B,
+    })
+}
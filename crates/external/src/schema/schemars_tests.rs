@@ -0,0 +1,55 @@
+//! NOTE: not under feature flagged schemars module to ensure tests are run
+//! with `cargo test`
+
+use schemars::{JsonSchema, schema_for};
+use serde_json::json;
+
+use crate::*;
+
+#[test]
+fn schema() {
+    let schema = schema_for!(PaddedNumber<2, 3>);
+
+    assert_eq!(
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "PaddedNumber_2_3",
+            "type": "string",
+            "pattern": "^[0-9]{2,3}$",
+            "minLength": 2,
+            "maxLength": 3,
+        }),
+        schema.to_value()
+    );
+}
+
+#[test]
+fn fixed_length_pattern() {
+    let schema = schema_for!(CompactPaddedNumber<4, 4>);
+    assert_eq!(Some(&json!("^[0-9]{4}$")), schema.get("pattern"));
+}
+
+#[test]
+fn empty_string_allowed() {
+    let schema = schema_for!(PaddedNumber<0, 2>);
+    assert_eq!(Some(&json!("^[0-9]{0,2}$")), schema.get("pattern"));
+    assert_eq!(Some(&json!(0)), schema.get("minLength"));
+}
+
+#[test]
+fn inlined_into_parent() {
+    #[derive(JsonSchema)]
+    #[allow(unused)]
+    struct Record {
+        id: PaddedNumber<2, 3>,
+        other_id: PaddedNumber<4, 4>,
+    }
+
+    let schema = schema_for!(Record);
+
+    assert_eq!(Some(&json!("^[0-9]{2,3}$")), schema.pointer("/properties/id/pattern"));
+    assert_eq!(
+        Some(&json!("^[0-9]{4}$")),
+        schema.pointer("/properties/other_id/pattern")
+    );
+}
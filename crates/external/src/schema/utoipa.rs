@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use ::utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{ObjectBuilder, RefOr, Schema, Type},
+};
+
+use crate::*;
+
+impl<const A: u8, const B: u8> PartialSchema for PaddedNumber<A, B> {
+    fn schema() -> RefOr<Schema> {
+        string_schema::<A, B>()
+    }
+}
+
+impl<const A: u8, const B: u8> ToSchema for PaddedNumber<A, B> {
+    fn name() -> Cow<'static, str> {
+        super::schema_name::<A, B>("PaddedNumber").into()
+    }
+}
+
+impl<const A: u8, const B: u8> PartialSchema for CompactPaddedNumber<A, B> {
+    fn schema() -> RefOr<Schema> {
+        string_schema::<A, B>()
+    }
+}
+
+impl<const A: u8, const B: u8> ToSchema for CompactPaddedNumber<A, B> {
+    fn name() -> Cow<'static, str> {
+        super::schema_name::<A, B>("CompactPaddedNumber").into()
+    }
+}
+
+fn string_schema<const A: u8, const B: u8>() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .pattern(Some(super::pattern::<A, B>()))
+        .min_length(Some(A as usize))
+        .max_length(Some(B as usize))
+        .into()
+}
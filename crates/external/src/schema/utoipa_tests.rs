@@ -0,0 +1,58 @@
+//! NOTE: not under feature flagged utoipa module to ensure tests are run
+//! with `cargo test`
+
+use serde_json::json;
+use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+use crate::*;
+
+#[test]
+fn schema() {
+    assert_eq!(
+        json!({
+            "type": "string",
+            "pattern": "^[0-9]{2,3}$",
+            "minLength": 2,
+            "maxLength": 3,
+        }),
+        serde_json::to_value(PaddedNumber::<2, 3>::schema()).unwrap()
+    );
+}
+
+#[test]
+fn fixed_length_pattern() {
+    let schema = serde_json::to_value(CompactPaddedNumber::<4, 4>::schema()).unwrap();
+    assert_eq!(Some(&json!("^[0-9]{4}$")), schema.get("pattern"));
+}
+
+#[test]
+fn empty_string_allowed() {
+    let schema = serde_json::to_value(PaddedNumber::<0, 2>::schema()).unwrap();
+    assert_eq!(Some(&json!("^[0-9]{0,2}$")), schema.get("pattern"));
+    assert_eq!(Some(&json!(0)), schema.get("minLength"));
+}
+
+#[test]
+fn components() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Record {
+        id: PaddedNumber<2, 3>,
+        other_id: PaddedNumber<4, 4>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Record)))]
+    struct ApiDoc;
+
+    let openapi = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        Some(&json!("^[0-9]{2,3}$")),
+        openapi.pointer("/components/schemas/PaddedNumber_2_3/pattern")
+    );
+    assert_eq!(
+        Some(&json!("^[0-9]{4}$")),
+        openapi.pointer("/components/schemas/PaddedNumber_4_4/pattern")
+    );
+}
@@ -19,7 +19,9 @@ impl<const MIN: u8, const MAX: u8> PaddedNumber<MIN, MAX> {
     /// #![feature(generic_const_exprs)]
     ///
     /// # use padded_number_macros::*;
-    /// let section = padded_number!("00123")
+    /// # use padded_number::PaddedNumber;
+    /// let number: PaddedNumber = padded_number!("00123");
+    /// let section = number
     ///     .checked_section::<2, 5>()
     ///     .expect("section should not have overflowed");
     ///
@@ -82,7 +84,9 @@ impl<const MIN: u8, const MAX: u8> PaddedNumber<MIN, MAX> {
     /// #![feature(generic_const_exprs)]
     ///
     /// # use padded_number_macros::*;
-    /// let section = padded_number!("00123")
+    /// # use padded_number::PaddedNumber;
+    /// let number: PaddedNumber = padded_number!("00123");
+    /// let section = number
     ///     .relaxed_section::<3, 10, 1>()
     ///     .expect("invalid min length");
     ///
@@ -65,7 +65,7 @@ fn expected_section() {
     let padded_number = PaddedNumber::<3, 5>::try_new("00123").unwrap();
 
     let actual_section = padded_number.expected_section::<0, 3>();
-    let expected_section = PaddedNumber::try_new("001").unwrap();
+    let expected_section = PaddedNumber::<3, 3>::try_new("001").unwrap();
 
     assert_eq!(expected_section, actual_section)
 }
@@ -77,6 +77,6 @@ where
 {
     let padded_number = PaddedNumber::<A, B>::try_new(number_str).unwrap();
     let actual_section = padded_number.checked_section::<C, D>().unwrap();
-    let expected_section = PaddedNumber::try_new(expected_section).unwrap();
+    let expected_section = PaddedNumber::<{ D - C }, { D - C }>::try_new(expected_section).unwrap();
     assert_eq!(expected_section, actual_section)
 }
@@ -0,0 +1,305 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::*;
+
+/// Identifier consisting of several padded number segments joined by a
+/// separator, e.g. `"2024-000123"` or `"01.002.0003"`
+///
+/// Segments are stored as a tuple of [`PaddedNumber`]s,
+/// each with their own length bounds, see [`Segments`]. Ordering is segment by
+/// segment, starting with the first one.
+///
+/// ```rust
+/// # use padded_number::*;
+/// type InvoiceNumber = PaddedSegments<(PaddedNumber<4, 4>, PaddedNumber<6, 6>)>;
+///
+/// let invoice_number = "2024-999999".parse::<InvoiceNumber>().unwrap();
+/// assert_eq!(
+///     "2025-000000",
+///     invoice_number.wrapping_increment().to_string()
+/// );
+///
+/// type Version = PaddedSegments<(PaddedNumber<2, 2>, PaddedNumber, PaddedNumber), '.'>;
+///
+/// let version = "01.002.0003".parse::<Version>().unwrap();
+/// assert!(version < "01.002.00003".parse().unwrap());
+/// assert!("01.2.3".parse::<Version>().is_ok());
+/// assert!("1.2.3".parse::<Version>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaddedSegments<T, const SEPARATOR: char = '-'>(pub T);
+
+impl<T: Segments, const SEPARATOR: char> PaddedSegments<T, SEPARATOR> {
+    /// Increment the last segment by one, carrying into the preceding segment
+    /// whenever a segment wraps around
+    ///
+    /// Segments wrap as in [`PaddedNumber::wrapping_add`]. The identifier as a
+    /// whole wraps around to its first value once every segment has wrapped.
+    ///
+    /// ```rust
+    /// # use padded_number::*;
+    /// type Id = PaddedSegments<(PaddedNumber<2, 2>, PaddedNumber<1, 2>)>;
+    ///
+    /// let segments = "05-99".parse::<Id>().unwrap();
+    /// assert_eq!("06-0", segments.wrapping_increment().to_string());
+    ///
+    /// let segments = "99-99".parse::<Id>().unwrap();
+    /// assert_eq!("00-0", segments.wrapping_increment().to_string());
+    /// ```
+    pub fn wrapping_increment(self) -> Self {
+        Self(self.0.increment().0)
+    }
+
+    /// Like [`Self::wrapping_increment`], but returns `None` if every segment
+    /// wrapped around
+    pub fn checked_increment(self) -> Option<Self> {
+        match self.0.increment() {
+            (segments, false) => Some(Self(segments)),
+            (_, true) => None,
+        }
+    }
+
+    /// Decrement the last segment by one, borrowing from the preceding
+    /// segment whenever a segment wraps around
+    ///
+    /// Segments wrap as in [`PaddedNumber::wrapping_sub`].
+    pub fn wrapping_decrement(self) -> Self {
+        Self(self.0.decrement().0)
+    }
+
+    /// Like [`Self::wrapping_decrement`], but returns `None` if every segment
+    /// wrapped around
+    pub fn checked_decrement(self) -> Option<Self> {
+        match self.0.decrement() {
+            (segments, false) => Some(Self(segments)),
+            (_, true) => None,
+        }
+    }
+}
+
+impl<T: Segments, const SEPARATOR: char> FromStr for PaddedSegments<T, SEPARATOR> {
+    type Err = ParsePaddedSegmentsError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let parts = str.split(SEPARATOR).collect::<Vec<_>>();
+
+        if parts.len() != T::COUNT {
+            return Err(ParsePaddedSegmentsError::SegmentCount(T::COUNT, SEPARATOR, parts.len()));
+        }
+
+        T::from_parts(&parts).map(Self)
+    }
+}
+
+impl<T: Segments, const SEPARATOR: char> TryFrom<&str> for PaddedSegments<T, SEPARATOR> {
+    type Error = ParsePaddedSegmentsError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        str.parse()
+    }
+}
+
+impl<T: Segments, const SEPARATOR: char> std::fmt::Display for PaddedSegments<T, SEPARATOR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.write_parts(SEPARATOR, f)
+    }
+}
+
+/// Tuples of [`PaddedNumber`]s usable as
+/// [`PaddedSegments`]
+///
+/// Implemented for tuples of one to six padded numbers.
+pub trait Segments: sealed::Sealed + Copy + Ord {
+    /// Number of segments
+    const COUNT: usize;
+
+    #[doc(hidden)]
+    fn from_parts(parts: &[&str]) -> Result<Self, ParsePaddedSegmentsError>;
+
+    #[doc(hidden)]
+    fn write_parts(&self, separator: char, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    /// Returns `true` alongside the segments if the first segment wrapped
+    #[doc(hidden)]
+    fn increment(self) -> (Self, bool);
+
+    /// Returns `true` alongside the segments if the first segment wrapped
+    #[doc(hidden)]
+    fn decrement(self) -> (Self, bool);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_segments {
+    ($count:literal; $($index:tt: $a:ident $b:ident),+; rev $($rev_index:tt),+) => {
+        impl<$(const $a: u8, const $b: u8),+> sealed::Sealed for ($(PaddedNumber<$a, $b>,)+) {}
+
+        impl<$(const $a: u8, const $b: u8),+> Segments for ($(PaddedNumber<$a, $b>,)+) {
+            const COUNT: usize = $count;
+
+            fn from_parts(parts: &[&str]) -> Result<Self, ParsePaddedSegmentsError> {
+                Ok(($(
+                    parts[$index]
+                        .parse()
+                        .map_err(|err| ParsePaddedSegmentsError::InvalidSegment($index, err))?,
+                )+))
+            }
+
+            fn write_parts(&self, separator: char, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                $(
+                    if $index > 0 {
+                        f.write_char(separator)?;
+                    }
+                    write!(f, "{}", self.$index)?;
+                )+
+
+                Ok(())
+            }
+
+            fn increment(mut self) -> (Self, bool) {
+                let mut carry = true;
+
+                $(
+                    if carry {
+                        let previous = self.$rev_index;
+                        self.$rev_index = previous.wrapping_add(1);
+                        // a single valued segment always carries
+                        carry = self.$rev_index <= previous;
+                    }
+                )+
+
+                (self, carry)
+            }
+
+            fn decrement(mut self) -> (Self, bool) {
+                let mut borrow = true;
+
+                $(
+                    if borrow {
+                        let current = self.$rev_index;
+                        (self.$rev_index, borrow) = match current.checked_sub(1) {
+                            // subtraction leaves empty segments as is
+                            Some(previous) if previous < current => (previous, false),
+                            _ => (PaddedNumber::LAST, true),
+                        };
+                    }
+                )+
+
+                (self, borrow)
+            }
+        }
+    };
+}
+
+impl_segments!(1; 0: A0 B0; rev 0);
+impl_segments!(2; 0: A0 B0, 1: A1 B1; rev 1, 0);
+impl_segments!(3; 0: A0 B0, 1: A1 B1, 2: A2 B2; rev 2, 1, 0);
+impl_segments!(4; 0: A0 B0, 1: A1 B1, 2: A2 B2, 3: A3 B3; rev 3, 2, 1, 0);
+impl_segments!(5; 0: A0 B0, 1: A1 B1, 2: A2 B2, 3: A3 B3, 4: A4 B4; rev 4, 3, 2, 1, 0);
+impl_segments!(6; 0: A0 B0, 1: A1 B1, 2: A2 B2, 3: A3 B3, 4: A4 B4, 5: A5 B5; rev 5, 4, 3, 2, 1, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_from_str;
+
+    type Mock = PaddedSegments<(PaddedNumber<2, 2>, PaddedNumber<1, 2>, PaddedNumber<1, 1>), '.'>;
+
+    fn mock(str: &str) -> Mock {
+        str.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let segments = mock("01.00.9");
+
+        assert_eq!(
+            (mock_from_str("01"), mock_from_str("00"), mock_from_str("9")),
+            segments.0
+        );
+        assert_eq!("01.00.9", segments.to_string());
+    }
+
+    #[test]
+    fn segment_count_error() {
+        assert_eq!(
+            Err(ParsePaddedSegmentsError::SegmentCount(3, '.', 2)),
+            "01.00".parse::<Mock>()
+        );
+        assert_eq!(
+            Err(ParsePaddedSegmentsError::SegmentCount(3, '.', 1)),
+            "01-00-0".parse::<Mock>()
+        );
+    }
+
+    #[test]
+    fn invalid_segment_error() {
+        assert_eq!(
+            Err(ParsePaddedSegmentsError::InvalidSegment(
+                1,
+                ParsePaddedNumberError::TooLong(2, 3)
+            )),
+            "01.000.0".parse::<Mock>()
+        );
+        assert!(matches!(
+            "01.00.x".parse::<Mock>(),
+            Err(ParsePaddedSegmentsError::InvalidSegment(
+                2,
+                ParsePaddedNumberError::InvalidNumber(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn ordered_segment_by_segment() {
+        assert!(mock("01.99.9") < mock("02.0.0"));
+        assert!(mock("01.9.9") < mock("01.00.0"));
+        assert!(mock("01.00.0") < mock("01.00.1"));
+    }
+
+    #[test]
+    fn increment_carries() {
+        assert_eq!(mock("01.0.1"), mock("01.0.0").wrapping_increment());
+        assert_eq!(mock("01.1.0"), mock("01.0.9").wrapping_increment());
+        assert_eq!(mock("01.00.0"), mock("01.9.9").wrapping_increment());
+        assert_eq!(mock("02.0.0"), mock("01.99.9").wrapping_increment());
+        assert_eq!(mock("00.0.0"), mock("99.99.9").wrapping_increment());
+
+        assert_eq!(None, mock("99.99.9").checked_increment());
+        assert_eq!(Some(mock("02.0.0")), mock("01.99.9").checked_increment());
+    }
+
+    #[test]
+    fn decrement_borrows() {
+        assert_eq!(mock("01.0.0"), mock("01.0.1").wrapping_decrement());
+        assert_eq!(mock("01.99.9"), mock("02.0.0").wrapping_decrement());
+        assert_eq!(mock("99.99.9"), mock("00.0.0").wrapping_decrement());
+
+        assert_eq!(None, mock("00.0.0").checked_decrement());
+    }
+
+    #[test]
+    fn single_valued_segment_carries() {
+        type Mock = PaddedSegments<(PaddedNumber<1, 1>, PaddedNumber<0, 0>)>;
+
+        let segments = "1-".parse::<Mock>().unwrap();
+        assert_eq!("2-", segments.wrapping_increment().to_string());
+        assert_eq!("0-", segments.wrapping_decrement().to_string());
+    }
+
+    #[test]
+    fn empty_segment_borrows() {
+        type Mock = PaddedSegments<(PaddedNumber<1, 1>, PaddedNumber<0, 2>)>;
+
+        let segments = "1-".parse::<Mock>().unwrap();
+        assert_eq!("0-99", segments.wrapping_decrement().to_string());
+
+        for str in ["1-", "0-99", "5-00", "9-"] {
+            let segments = str.parse::<Mock>().unwrap();
+            assert_eq!(segments, segments.wrapping_increment().wrapping_decrement());
+            assert_eq!(segments, segments.wrapping_decrement().wrapping_increment());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    fs::File,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{ordinal::OrdinalBounds, *};
+
+/// Persistent generator of padded numbers in ascending order, e.g. for invoice
+/// or ticket numbers
+///
+/// The state is a text file containing the next number to hand out, or
+/// `exhausted` once the sequence has run out of numbers. Each update first
+/// writes a temporary file which is then renamed over the state file, so a
+/// crash never leaves a partially written state behind. Concurrent access, be
+/// it from threads or processes, is serialized with an exclusive lock on a
+/// sibling `.lock` file.
+///
+/// Numbers are handed out in blocks, the state being advanced past the entire
+/// block before any number in it is used. A crash may therefore leave gaps in
+/// the sequence, but numbers are never handed out twice. That is, except when
+/// wrapping around with [`OverflowStrategy::Wrap`].
+///
+/// [`OverflowStrategy::Saturate`] behaves as [`OverflowStrategy::Fail`]:
+/// the last number is handed out once, after which the sequence is exhausted,
+/// rather than handing out the last number over and over again.
+///
+/// ```rust
+/// # use padded_number::*;
+/// # let dir = std::env::temp_dir().join("padded-number-sequence-doc");
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// # let path = dir.join("invoices");
+/// # let _ = std::fs::remove_file(&path);
+/// let first = bound_padded_number!(4, 4, "0001");
+/// let sequence = PaddedSequence::open(&path, first, OverflowStrategy::Fail).unwrap();
+///
+/// assert_eq!(first, sequence.next_number().unwrap());
+///
+/// let block = sequence.lease(3).unwrap();
+/// let numbers = block.map(|number| number.to_string()).collect::<Vec<_>>();
+/// assert_eq!(["0002", "0003", "0004"], numbers.as_slice());
+///
+/// // state survives reopening, `first` is then ignored
+/// let sequence = PaddedSequence::open(&path, first, OverflowStrategy::Fail).unwrap();
+/// assert_eq!(
+///     bound_padded_number!(4, 4, "0005"),
+///     sequence.next_number().unwrap()
+/// );
+/// ```
+///
+/// Like [`CompactPaddedNumber`], the upper length bound may not exceed 19.
+#[derive(Debug, Clone)]
+pub struct PaddedSequence<const A: u8 = 1, const B: u8 = 19> {
+    path: PathBuf,
+    overflow: OverflowStrategy,
+}
+
+impl<const A: u8, const B: u8> PaddedSequence<A, B> {
+    const EXHAUSTED: &str = "exhausted";
+
+    /// Open the sequence stored at `path`, starting it at `first` if it does
+    /// not yet exist
+    pub fn open(
+        path: impl Into<PathBuf>,
+        first: PaddedNumber<A, B>,
+        overflow: OverflowStrategy,
+    ) -> Result<Self, SequenceError> {
+        let sequence = Self { path: path.into(), overflow };
+
+        let _lock = sequence.lock()?;
+
+        if !sequence.path.try_exists()? {
+            sequence.write_state(Some(CompactPaddedNumber::from_padded_number(first)))?;
+        }
+
+        // fail early on invalid states
+        sequence.read_state()?;
+
+        Ok(sequence)
+    }
+
+    /// Take the next number of the sequence
+    pub fn next_number(&self) -> Result<PaddedNumber<A, B>, SequenceError> {
+        self.lease(1)?.next().ok_or(SequenceError::Exhausted)
+    }
+
+    /// Look at the next number of the sequence without taking it
+    ///
+    /// Returns `None` if the sequence is exhausted.
+    pub fn peek(&self) -> Result<Option<PaddedNumber<A, B>>, SequenceError> {
+        let _lock = self.lock()?;
+        Ok(self.read_state()?.map(CompactPaddedNumber::into_padded_number))
+    }
+
+    /// Reserve a block of `count` consecutive numbers, e.g. for a worker to
+    /// hand out without having to access the state for each number
+    ///
+    /// Unless wrapping, a shorter block is returned if fewer than `count`
+    /// numbers remain, and [`SequenceError::Exhausted`] once none remain.
+    pub fn lease(&self, count: u64) -> Result<SequenceBlock<A, B>, SequenceError> {
+        let _lock = self.lock()?;
+
+        let next = self.read_state()?.ok_or(SequenceError::Exhausted)?;
+
+        if count == 0 {
+            return Ok(SequenceBlock { next, remaining: 0, overflow: self.overflow });
+        }
+
+        let (count, new_state) = match self.overflow {
+            OverflowStrategy::Wrap => (count, Some(next.wrapping_add(count))),
+            // saturating at the last number would hand it out repeatedly
+            OverflowStrategy::Saturate | OverflowStrategy::Fail => {
+                let available = OrdinalBounds::new(A, B).end() - next.ordinal() + 1;
+
+                match next.checked_add(count) {
+                    Some(new_next) => (count, Some(new_next)),
+                    None => (available.min(count), None),
+                }
+            }
+        };
+
+        self.write_state(new_state)?;
+
+        Ok(SequenceBlock { next, remaining: count, overflow: self.overflow })
+    }
+
+    fn lock(&self) -> Result<File, SequenceError> {
+        let lock_file = File::create(self.sibling_path("lock"))?;
+        lock_file.lock()?;
+        Ok(lock_file)
+    }
+
+    fn read_state(&self) -> Result<Option<CompactPaddedNumber<A, B>>, SequenceError> {
+        let contents = std::fs::read_to_string(&self.path)?;
+        let state = contents.trim_end_matches('\n');
+
+        if state == Self::EXHAUSTED {
+            return Ok(None);
+        }
+
+        CompactPaddedNumber::try_new(state)
+            .map(Some)
+            .map_err(|err| SequenceError::InvalidState(state.to_string(), err))
+    }
+
+    fn write_state(&self, state: Option<CompactPaddedNumber<A, B>>) -> Result<(), SequenceError> {
+        let temporary_path = self.sibling_path("tmp");
+
+        let mut temporary_file = File::create(&temporary_path)?;
+        match state {
+            Some(next) => writeln!(temporary_file, "{next}")?,
+            None => writeln!(temporary_file, "{}", Self::EXHAUSTED)?,
+        }
+        temporary_file.sync_all()?;
+
+        std::fs::rename(&temporary_path, &self.path)?;
+        sync_parent_dir(&self.path)?;
+
+        Ok(())
+    }
+
+    fn sibling_path(&self, extension: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".");
+        path.push(extension);
+        path.into()
+    }
+}
+
+/// Ensures that the rename itself is persisted
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    match File::open(parent)?.sync_all() {
+        // some file systems do not support syncing directories
+        Err(err) if err.kind() == ErrorKind::InvalidInput => Ok(()),
+        result => result,
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Block of consecutive numbers leased from a [`PaddedSequence`]
+///
+/// Numbers of a block left unused are skipped by the sequence.
+#[derive(Debug, Clone)]
+pub struct SequenceBlock<const A: u8 = 1, const B: u8 = 19> {
+    next: CompactPaddedNumber<A, B>,
+    remaining: u64,
+    overflow: OverflowStrategy,
+}
+
+impl<const A: u8, const B: u8> Iterator for SequenceBlock<A, B> {
+    type Item = PaddedNumber<A, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let current = self.next;
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            self.next = match self.overflow {
+                OverflowStrategy::Wrap => current.wrapping_add(1),
+                // blocks of other sequences never extend past the bounds
+                OverflowStrategy::Saturate | OverflowStrategy::Fail => current.saturating_add(1),
+            };
+        }
+
+        Some(current.into_padded_number())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
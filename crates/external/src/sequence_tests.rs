@@ -0,0 +1,182 @@
+//! NOTE: not under feature flagged sequence module to ensure tests are run
+//! with `cargo test`
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use crate::{tests::mock_from_str, *};
+
+/// Fresh directory for a single test
+fn mock_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("padded-number-sequence-tests")
+        .join(format!("{name}-{}", std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+fn open(path: &Path, overflow: OverflowStrategy) -> PaddedSequence<2, 3> {
+    PaddedSequence::open(path, mock_from_str("97"), overflow).unwrap()
+}
+
+fn collect<const A: u8, const B: u8>(block: SequenceBlock<A, B>) -> Vec<String> {
+    block.map(|number| number.to_string()).collect()
+}
+
+#[test]
+fn hands_out_in_order() {
+    let path = mock_dir("in_order").join("state");
+    let sequence = open(&path, OverflowStrategy::Fail);
+
+    assert_eq!(mock_from_str::<2, 3>("97"), sequence.next_number().unwrap());
+    assert_eq!(["98", "99", "000"], collect(sequence.lease(3).unwrap()).as_slice());
+    assert_eq!(Some(mock_from_str("001")), sequence.peek().unwrap());
+    assert_eq!("001\n", std::fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn fail_strategy() {
+    let path = mock_dir("fail").join("state");
+    let sequence = PaddedSequence::<2, 2>::open(&path, mock_from_str("97"), OverflowStrategy::Fail).unwrap();
+
+    // truncated to the remaining numbers
+    assert_eq!(3, sequence.lease(5).unwrap().count());
+    assert_eq!(None, sequence.peek().unwrap());
+    assert!(matches!(sequence.lease(1), Err(SequenceError::Exhausted)));
+    assert!(matches!(sequence.next_number(), Err(SequenceError::Exhausted)));
+}
+
+#[test]
+fn fail_strategy_exact_fit() {
+    let path = mock_dir("fail_exact").join("state");
+    let sequence = PaddedSequence::<2, 2>::open(&path, mock_from_str("97"), OverflowStrategy::Fail).unwrap();
+
+    assert_eq!(3, sequence.lease(3).unwrap().count());
+    assert!(matches!(sequence.next_number(), Err(SequenceError::Exhausted)));
+}
+
+#[test]
+fn wrap_strategy() {
+    let path = mock_dir("wrap").join("state");
+    let sequence = PaddedSequence::<2, 2>::open(&path, mock_from_str("98"), OverflowStrategy::Wrap).unwrap();
+
+    assert_eq!(["98", "99", "00", "01"], collect(sequence.lease(4).unwrap()).as_slice());
+    assert_eq!(Some(mock_from_str("02")), sequence.peek().unwrap());
+}
+
+#[test]
+fn saturate_strategy() {
+    let path = mock_dir("saturate").join("state");
+    let sequence = PaddedSequence::<2, 2>::open(&path, mock_from_str("98"), OverflowStrategy::Saturate).unwrap();
+
+    // the last number is handed out once, as with the fail strategy
+    assert_eq!(["98", "99"], collect(sequence.lease(3).unwrap()).as_slice());
+    assert_eq!(None, sequence.peek().unwrap());
+    assert!(matches!(sequence.next_number(), Err(SequenceError::Exhausted)));
+}
+
+#[test]
+fn empty_lease_leaves_state_untouched() {
+    let path = mock_dir("empty_lease").join("state");
+    let sequence = open(&path, OverflowStrategy::Fail);
+
+    assert_eq!(0, sequence.lease(0).unwrap().count());
+    assert_eq!(Some(mock_from_str("97")), sequence.peek().unwrap());
+}
+
+#[test]
+fn recovers_unused_block_as_gap() {
+    let path = mock_dir("unused_block").join("state");
+
+    {
+        let sequence = open(&path, OverflowStrategy::Fail);
+        let mut block = sequence.lease(3).unwrap();
+        assert_eq!(Some(mock_from_str("97")), block.next());
+        // crash before the rest of the block is used
+    }
+
+    let sequence = open(&path, OverflowStrategy::Fail);
+    assert_eq!(mock_from_str::<2, 3>("000"), sequence.next_number().unwrap());
+}
+
+#[test]
+fn recovers_from_interrupted_write() {
+    let path = mock_dir("interrupted_write").join("state");
+    open(&path, OverflowStrategy::Fail).lease(2).unwrap();
+
+    // crash after the temporary file was partially written, but before the
+    // rename, leaving stale temporary and lock files behind
+    std::fs::write(path.with_extension("tmp"), "00").unwrap();
+    std::fs::write(path.with_extension("lock"), "").unwrap();
+
+    let sequence = open(&path, OverflowStrategy::Fail);
+    assert_eq!(mock_from_str::<2, 3>("99"), sequence.next_number().unwrap());
+    assert_eq!(mock_from_str::<2, 3>("000"), sequence.next_number().unwrap());
+}
+
+#[test]
+fn rejects_torn_state() {
+    let path = mock_dir("torn_state").join("state");
+    open(&path, OverflowStrategy::Fail).lease(2).unwrap();
+
+    // state file emptied or cut short, e.g. by a file system without atomic
+    // renames, is not mistaken for a fresh sequence
+    for (contents, state) in [("", ""), ("\n", ""), ("9", "9")] {
+        std::fs::write(&path, contents).unwrap();
+
+        let err = PaddedSequence::<2, 3>::open(&path, mock_from_str("97"), OverflowStrategy::Fail).unwrap_err();
+        assert!(matches!(&err, SequenceError::InvalidState(invalid_state, _) if invalid_state == state));
+        assert_eq!(contents, std::fs::read_to_string(&path).unwrap());
+    }
+}
+
+#[test]
+fn rejects_invalid_state() {
+    let path = mock_dir("invalid_state").join("state");
+    std::fs::write(&path, "1\n").unwrap();
+
+    let err = PaddedSequence::<2, 3>::open(&path, mock_from_str("00"), OverflowStrategy::Fail).unwrap_err();
+    assert!(matches!(err, SequenceError::InvalidState(state, ParsePaddedNumberError::TooShort(2, 1)) if state == "1"));
+}
+
+#[test]
+fn concurrent_workers() {
+    const WORKERS: usize = 4;
+    const LEASES: usize = 25;
+    const BLOCK_SIZE: u64 = 3;
+
+    let path = mock_dir("concurrent").join("state");
+    PaddedSequence::<4, 4>::open(&path, mock_from_str("0000"), OverflowStrategy::Fail).unwrap();
+
+    let numbers = std::thread::scope(|scope| {
+        let workers = (0..WORKERS)
+            .map(|_| {
+                scope.spawn(|| {
+                    let sequence =
+                        PaddedSequence::<4, 4>::open(&path, mock_from_str("0000"), OverflowStrategy::Fail).unwrap();
+
+                    (0..LEASES)
+                        .flat_map(|_| sequence.lease(BLOCK_SIZE).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let unique_numbers = numbers.iter().collect::<BTreeSet<_>>();
+    let expected_count = WORKERS * LEASES * BLOCK_SIZE as usize;
+
+    assert_eq!(expected_count, numbers.len());
+    assert_eq!(expected_count, unique_numbers.len());
+    assert_eq!(Some(&&mock_from_str("0299")), unique_numbers.last());
+}
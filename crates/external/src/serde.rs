@@ -1,22 +1,146 @@
-use ::serde::{Deserialize, Serialize};
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, Unexpected, Visitor},
+};
 
 use crate::*;
 
+/// Serialized as a string for human-readable formats, and as a `(length,
+/// number)` tuple for all others.
+///
+/// Deserialization is done correspondingly, but additionally accepts
+/// unsigned integers for human-readable formats if the length bounds are equal.
+/// The length is then fixed, so the leading zeros can be deduced.
 impl<const A: u8, const B: u8> Serialize for PaddedNumber<A, B> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        S: ::serde::Serializer,
+        S: Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        match serializer.is_human_readable() {
+            true => serialize_str(self, serializer),
+            false => serialize_parts(self, serializer),
+        }
     }
 }
 
 impl<'de, const A: u8, const B: u8> Deserialize<'de> for PaddedNumber<A, B> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: ::serde::Deserializer<'de>,
+        D: Deserializer<'de>,
     {
-        let str = std::borrow::Cow::<str>::deserialize(deserializer)?;
-        str.as_ref().parse().map_err(::serde::de::Error::custom)
+        match deserializer.is_human_readable() {
+            true => deserialize_str_or_integer(deserializer),
+            false => deserialize_parts(deserializer),
+        }
+    }
+}
+
+/// Serialized as a string for human-readable formats, and as its
+/// [ordinal](CompactPaddedNumber::ordinal) for all others.
+impl<const A: u8, const B: u8> Serialize for CompactPaddedNumber<A, B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match serializer.is_human_readable() {
+            true => serialize_str(&self.into_padded_number(), serializer),
+            false => serializer.serialize_u64(self.ordinal()),
+        }
+    }
+}
+
+impl<'de, const A: u8, const B: u8> Deserialize<'de> for CompactPaddedNumber<A, B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserialize_str_or_integer(deserializer).map(Self::from_padded_number),
+            false => {
+                let ordinal = u64::deserialize(deserializer)?;
+                Self::try_from_ordinal(ordinal).map_err(D::Error::custom)
+            }
+        }
+    }
+}
+
+pub(crate) fn serialize_str<S: Serializer, const A: u8, const B: u8>(
+    padded_number: &PaddedNumber<A, B>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(padded_number)
+}
+
+pub(crate) fn serialize_parts<S: Serializer, const A: u8, const B: u8>(
+    padded_number: &PaddedNumber<A, B>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    (padded_number.len(), padded_number.number).serialize(serializer)
+}
+
+pub(crate) fn deserialize_str<'de, D: Deserializer<'de>, const A: u8, const B: u8>(
+    deserializer: D,
+) -> Result<PaddedNumber<A, B>, D::Error> {
+    deserializer.deserialize_str(PaddedNumberVisitor { accept_integers: false })
+}
+
+pub(crate) fn deserialize_str_or_integer<'de, D: Deserializer<'de>, const A: u8, const B: u8>(
+    deserializer: D,
+) -> Result<PaddedNumber<A, B>, D::Error> {
+    match A == B {
+        true => deserializer.deserialize_any(PaddedNumberVisitor { accept_integers: true }),
+        false => deserialize_str(deserializer),
+    }
+}
+
+pub(crate) fn deserialize_parts<'de, D: Deserializer<'de>, const A: u8, const B: u8>(
+    deserializer: D,
+) -> Result<PaddedNumber<A, B>, D::Error> {
+    let (length, number) = <(u8, u64)>::deserialize(deserializer)?;
+    PaddedNumber::try_from_parts(length, number).map_err(D::Error::custom)
+}
+
+#[cfg(feature = "serde_with")]
+pub(crate) fn deserialize_integer<'de, D: Deserializer<'de>, const A: u8, const B: u8>(
+    deserializer: D,
+) -> Result<PaddedNumber<A, B>, D::Error> {
+    deserializer.deserialize_u64(PaddedNumberVisitor { accept_integers: true })
+}
+
+struct PaddedNumberVisitor<const A: u8, const B: u8> {
+    accept_integers: bool,
+}
+
+impl<const A: u8, const B: u8> Visitor<'_> for PaddedNumberVisitor<A, B> {
+    type Value = PaddedNumber<A, B>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match (A == B, self.accept_integers) {
+            (true, true) => write!(
+                formatter,
+                "a string of exactly {A} digits, or an unsigned integer of at most {A} digits"
+            ),
+            (true, false) => write!(formatter, "a string of exactly {A} digits"),
+            (false, _) => write!(formatter, "a string of {A} to {B} digits"),
+        }
+    }
+
+    fn visit_str<E: Error>(self, str: &str) -> Result<Self::Value, E> {
+        PaddedNumber::try_new(str).map_err(E::custom)
+    }
+
+    fn visit_u64<E: Error>(self, number: u64) -> Result<Self::Value, E> {
+        if !self.accept_integers || A != B {
+            return Err(E::invalid_type(Unexpected::Unsigned(number), &self));
+        }
+
+        PaddedNumber::try_from_parts(A, number).map_err(E::custom)
+    }
+
+    fn visit_i64<E: Error>(self, number: i64) -> Result<Self::Value, E> {
+        match u64::try_from(number) {
+            Ok(number) => self.visit_u64(number),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(number), &self)),
+        }
     }
 }
@@ -1,6 +1,8 @@
 //! NOTE: not under feature flagged serde module to ensure tests are run with
 //! `cargo test`
 
+use serde_test::{Configure, Token, assert_de_tokens_error, assert_tokens};
+
 use crate::{tests::mock_from_str, *};
 
 fn mock_number() -> PaddedNumber<1, 4> {
@@ -13,7 +15,7 @@ fn mock_json_str() -> &'static str {
 
 #[test]
 fn str_deserialization() {
-    let deserialized_number = serde_json::from_str(mock_json_str()).unwrap();
+    let deserialized_number: PaddedNumber<1, 4> = serde_json::from_str(mock_json_str()).unwrap();
     assert_eq!(mock_number(), deserialized_number);
 }
 
@@ -22,3 +24,81 @@ fn str_serialization() {
     let actual_json = serde_json::to_string(&mock_number()).unwrap();
     assert_eq!(mock_json_str(), actual_json);
 }
+
+#[test]
+fn readable_tokens() {
+    assert_tokens(&mock_number().readable(), &[Token::Str("0123")]);
+}
+
+#[test]
+fn compact_tokens() {
+    assert_tokens(
+        &mock_number().compact(),
+        &[Token::Tuple { len: 2 }, Token::U8(4), Token::U64(123), Token::TupleEnd],
+    );
+}
+
+#[test]
+fn compact_tokens_error() {
+    assert_de_tokens_error::<serde_test::Compact<PaddedNumber<1, 3>>>(
+        &[Token::Tuple { len: 2 }, Token::U8(4), Token::U64(123), Token::TupleEnd],
+        &DecodePaddedNumberError::TooLong(3, 4).to_string(),
+    );
+}
+
+#[test]
+fn integer_deserialization() {
+    let deserialized_number: PaddedNumber<4, 4> = serde_json::from_str("123").unwrap();
+    assert_eq!(mock_from_str::<4, 4>("0123"), deserialized_number);
+
+    let deserialized_number: PaddedNumber<2, 2> = serde_json::from_str("0").unwrap();
+    assert_eq!(mock_from_str::<2, 2>("00"), deserialized_number);
+}
+
+#[test]
+fn integer_deserialization_errors() {
+    assert_error_message::<PaddedNumber<2, 2>>("123", &DecodePaddedNumberError::NumberTooLong(2, 123).to_string());
+    assert_error_message::<PaddedNumber<2, 2>>(
+        "-1",
+        "invalid value: integer `-1`, expected a string of exactly 2 digits, or an unsigned integer of at most 2 digits",
+    );
+    assert_error_message::<PaddedNumber<1, 4>>(
+        "123",
+        "invalid type: integer `123`, expected a string of 1 to 4 digits",
+    );
+}
+
+#[test]
+fn str_deserialization_errors() {
+    assert_error_message::<PaddedNumber<1, 4>>("\"01234\"", &ParsePaddedNumberError::TooLong(4, 5).to_string());
+    assert_error_message::<PaddedNumber<2, 2>>("\"1\"", &ParsePaddedNumberError::TooShort(2, 1).to_string());
+    assert_error_message::<PaddedNumber<1, 4>>(
+        "\"01a\"",
+        &PaddedNumber::<1, 4>::try_new("01a").unwrap_err().to_string(),
+    );
+}
+
+fn assert_error_message<T: ::serde::de::DeserializeOwned + std::fmt::Debug>(json: &str, expected_message: &str) {
+    let error = serde_json::from_str::<T>(json).unwrap_err();
+    assert!(
+        error.to_string().starts_with(expected_message),
+        "'{error}' does not start with '{expected_message}'"
+    );
+}
+
+#[test]
+fn compact_round_trip() {
+    let compact = CompactPaddedNumber::from_padded_number(mock_number());
+
+    let actual_json = serde_json::to_string(&compact).unwrap();
+    assert_eq!(mock_json_str(), actual_json);
+
+    let deserialized_compact: CompactPaddedNumber<1, 4> = serde_json::from_str(&actual_json).unwrap();
+    assert_eq!(compact, deserialized_compact);
+}
+
+#[test]
+fn compact_ordinal_tokens() {
+    let compact = CompactPaddedNumber::from_padded_number(mock_number());
+    assert_tokens(&compact.compact(), &[Token::U64(1234)]);
+}
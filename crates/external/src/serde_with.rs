@@ -0,0 +1,76 @@
+//! Adapters for choosing the serde representation of a `PaddedNumber` per
+//! field, by the means of `serde_with::serde_as`.
+//!
+//! ```rust
+//! # use padded_number::{PaddedNumber, serde_with::*};
+//! # use serde::{Deserialize, Serialize};
+//! #[serde_with::serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     #[serde_as(as = "AsInteger")]
+//!     number: PaddedNumber<6, 6>,
+//!     #[serde_as(as = "AsString")]
+//!     customer: PaddedNumber<1, 4>,
+//! }
+//!
+//! let json = r#"{"number":42,"customer":"0012"}"#;
+//! let invoice: Invoice = serde_json::from_str(json).unwrap();
+//!
+//! assert_eq!(invoice.number.to_string(), "000042");
+//! assert_eq!(serde_json::to_string(&invoice).unwrap(), json);
+//! ```
+
+use ::serde::{Deserializer, Serializer};
+use ::serde_with::{DeserializeAs, SerializeAs};
+
+use crate::{serde::*, *};
+
+/// Represent a padded number as a string, regardless of the format being
+/// human-readable or not
+pub struct AsString;
+
+impl<const A: u8, const B: u8> SerializeAs<PaddedNumber<A, B>> for AsString {
+    fn serialize_as<S: Serializer>(source: &PaddedNumber<A, B>, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_str(source, serializer)
+    }
+}
+
+impl<'de, const A: u8, const B: u8> DeserializeAs<'de, PaddedNumber<A, B>> for AsString {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PaddedNumber<A, B>, D::Error> {
+        deserialize_str(deserializer)
+    }
+}
+
+/// Represent a padded number as a `(length, number)` tuple, regardless of the
+/// format being human-readable or not
+pub struct AsParts;
+
+impl<const A: u8, const B: u8> SerializeAs<PaddedNumber<A, B>> for AsParts {
+    fn serialize_as<S: Serializer>(source: &PaddedNumber<A, B>, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_parts(source, serializer)
+    }
+}
+
+impl<'de, const A: u8, const B: u8> DeserializeAs<'de, PaddedNumber<A, B>> for AsParts {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PaddedNumber<A, B>, D::Error> {
+        deserialize_parts(deserializer)
+    }
+}
+
+/// Represent a fixed length padded number as an unsigned integer
+///
+/// The leading zeros are implied by the length, which is why only padded
+/// numbers with equal length bounds are supported.
+pub struct AsInteger;
+
+impl<const N: u8> SerializeAs<PaddedNumber<N, N>> for AsInteger {
+    fn serialize_as<S: Serializer>(source: &PaddedNumber<N, N>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(source.number)
+    }
+}
+
+impl<'de, const N: u8> DeserializeAs<'de, PaddedNumber<N, N>> for AsInteger {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PaddedNumber<N, N>, D::Error> {
+        deserialize_integer(deserializer)
+    }
+}
@@ -0,0 +1,48 @@
+//! NOTE: not under feature flagged serde_with module to ensure tests are run
+//! with `cargo test`
+
+use ::serde::{Deserialize, Serialize};
+use serde_test::{Configure, Token, assert_tokens};
+
+use crate::{serde_with::*, tests::mock_from_str, *};
+
+#[::serde_with::serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    #[serde_as(as = "AsString")]
+    string: PaddedNumber<1, 4>,
+    #[serde_as(as = "AsParts")]
+    parts: PaddedNumber<1, 4>,
+    #[serde_as(as = "AsInteger")]
+    integer: PaddedNumber<4, 4>,
+}
+
+fn mock_record() -> Record {
+    Record {
+        string: mock_from_str("012"),
+        parts: mock_from_str("012"),
+        integer: mock_from_str("0012"),
+    }
+}
+
+fn mock_tokens() -> [Token; 11] {
+    [
+        Token::Struct { name: "Record", len: 3 },
+        Token::Str("string"),
+        Token::Str("012"),
+        Token::Str("parts"),
+        Token::Tuple { len: 2 },
+        Token::U8(3),
+        Token::U64(12),
+        Token::TupleEnd,
+        Token::Str("integer"),
+        Token::U64(12),
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn representation_independent_of_format() {
+    assert_tokens(&mock_record().readable(), &mock_tokens());
+    assert_tokens(&mock_record().compact(), &mock_tokens());
+}
@@ -0,0 +1,50 @@
+#[cfg(any(feature = "diesel-mysql", feature = "diesel-postgres", feature = "diesel-sqlite"))]
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    sql_types::Text,
+};
+
+use crate::*;
+
+#[cfg(feature = "diesel-mysql")]
+impl<const A: u8, const B: u8> ToSql<Text, ::diesel::mysql::Mysql> for PaddedNumber<A, B> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::mysql::Mysql>) -> serialize::Result {
+        use std::io::Write;
+
+        write!(out, "{self}")?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl<const A: u8, const B: u8> ToSql<Text, ::diesel::pg::Pg> for PaddedNumber<A, B> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::pg::Pg>) -> serialize::Result {
+        use std::io::Write;
+
+        write!(out, "{self}")?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl<const A: u8, const B: u8> ToSql<Text, ::diesel::sqlite::Sqlite> for PaddedNumber<A, B> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+/// Values violating the length bounds result in a deserialization error
+/// containing the [`ParsePaddedNumberError`].
+impl<DB, const A: u8, const B: u8> FromSql<Text, DB> for PaddedNumber<A, B>
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let string = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(string.parse::<Self>()?)
+    }
+}
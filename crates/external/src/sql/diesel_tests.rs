@@ -0,0 +1,60 @@
+//! NOTE: not under feature flagged diesel module to ensure tests are run with
+//! `cargo test`
+
+use diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+
+use crate::{tests::mock_from_str, *};
+
+diesel::table! {
+    numbers (id) {
+        id -> Integer,
+        number -> Text,
+    }
+}
+
+fn mock_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    sql_query("CREATE TABLE numbers (id INTEGER PRIMARY KEY, number TEXT NOT NULL)")
+        .execute(&mut connection)
+        .unwrap();
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let mut connection = mock_connection();
+    let number = mock_from_str::<1, 4>("0012");
+
+    diesel::insert_into(numbers::table)
+        .values(numbers::number.eq(number))
+        .execute(&mut connection)
+        .unwrap();
+
+    let stored: String = numbers::table.select(numbers::number).first(&mut connection).unwrap();
+    assert_eq!("0012", stored);
+
+    let read: PaddedNumber<1, 4> = numbers::table.select(numbers::number).first(&mut connection).unwrap();
+    assert_eq!(number, read);
+}
+
+#[test]
+fn length_bound_error() {
+    let mut connection = mock_connection();
+
+    diesel::insert_into(numbers::table)
+        .values(numbers::number.eq("0012"))
+        .execute(&mut connection)
+        .unwrap();
+
+    let err = numbers::table
+        .select(numbers::number)
+        .first::<PaddedNumber<1, 3>>(&mut connection)
+        .unwrap_err();
+
+    let diesel::result::Error::DeserializationError(source) = err else {
+        panic!("unexpected error: {err}");
+    };
+    // wrapped in a `DeserializeFieldError` naming the column
+    let source = std::error::Error::source(&*source).unwrap();
+    assert_eq!(ParsePaddedNumberError::TooLong(3, 4).to_string(), source.to_string());
+}
@@ -0,0 +1,19 @@
+//! SQL database integrations
+//!
+//! Padded numbers are always stored as text. Storing them as integers would
+//! silently drop any leading zeros.
+
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(test)]
+mod diesel_tests;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(test)]
+mod rusqlite_tests;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(test)]
+mod sqlx_tests;
@@ -0,0 +1,21 @@
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::*;
+
+/// Stored as `TEXT`
+impl<const A: u8, const B: u8> ToSql for PaddedNumber<A, B> {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+/// Read from `TEXT` only, values violating the length bounds result in a
+/// [`FromSqlError::Other`] containing the [`ParsePaddedNumberError`].
+impl<const A: u8, const B: u8> FromSql for PaddedNumber<A, B> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map_err(|err: ParsePaddedNumberError| FromSqlError::Other(Box::new(err)))
+    }
+}
@@ -0,0 +1,61 @@
+//! NOTE: not under feature flagged rusqlite module to ensure tests are run
+//! with `cargo test`
+
+use rusqlite::Connection;
+
+use crate::{tests::mock_from_str, *};
+
+fn mock_connection() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+    connection
+        .execute("CREATE TABLE numbers (number TEXT NOT NULL)", ())
+        .unwrap();
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let connection = mock_connection();
+    let number = mock_from_str::<1, 4>("0012");
+
+    connection.execute("INSERT INTO numbers VALUES (?1)", [number]).unwrap();
+
+    let stored: String = connection
+        .query_row("SELECT number FROM numbers", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!("0012", stored);
+
+    let read: PaddedNumber<1, 4> = connection
+        .query_row("SELECT number FROM numbers", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(number, read);
+}
+
+#[test]
+fn length_bound_error() {
+    let connection = mock_connection();
+    connection.execute("INSERT INTO numbers VALUES ('0012')", ()).unwrap();
+
+    let err = connection
+        .query_row("SELECT number FROM numbers", (), |row| {
+            row.get::<_, PaddedNumber<1, 3>>(0)
+        })
+        .unwrap_err();
+
+    let rusqlite::Error::FromSqlConversionFailure(_, _, source) = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(ParsePaddedNumberError::TooLong(3, 4).to_string(), source.to_string());
+}
+
+#[test]
+fn integer_error() {
+    let err = mock_connection()
+        .query_row("SELECT 12", (), |row| row.get::<_, PaddedNumber>(0))
+        .unwrap_err();
+
+    assert!(
+        matches!(err, rusqlite::Error::InvalidColumnType(..)),
+        "unexpected error: {err}"
+    );
+}
@@ -0,0 +1,38 @@
+use ::sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+use crate::*;
+
+/// Uses the same SQL type as `str`
+impl<DB: Database, const A: u8, const B: u8> Type<DB> for PaddedNumber<A, B>
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(type_info: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(type_info)
+    }
+}
+
+impl<'q, DB: Database, const A: u8, const B: u8> Encode<'q, DB> for PaddedNumber<A, B>
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buffer: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode(buffer)
+    }
+}
+
+/// Values violating the length bounds result in a decode error containing
+/// the [`ParsePaddedNumberError`].
+impl<'r, DB: Database, const A: u8, const B: u8> Decode<'r, DB> for PaddedNumber<A, B>
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let str = <&str as Decode<DB>>::decode(value)?;
+        Ok(str.parse::<Self>()?)
+    }
+}
@@ -0,0 +1,59 @@
+//! NOTE: not under feature flagged sqlx module to ensure tests are run with
+//! `cargo test`
+
+use sqlx::{Connection, SqliteConnection};
+
+use crate::{tests::mock_from_str, *};
+
+async fn mock_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE numbers (number TEXT NOT NULL)")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+    connection
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn round_trip() {
+    let mut connection = mock_connection().await;
+    let number = mock_from_str::<1, 4>("0012");
+
+    sqlx::query("INSERT INTO numbers VALUES (?)")
+        .bind(number)
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    let stored: String = sqlx::query_scalar("SELECT number FROM numbers")
+        .fetch_one(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!("0012", stored);
+
+    let read: PaddedNumber<1, 4> = sqlx::query_scalar("SELECT number FROM numbers")
+        .fetch_one(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(number, read);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn length_bound_error() {
+    let mut connection = mock_connection().await;
+
+    sqlx::query("INSERT INTO numbers VALUES ('0012')")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    let err = sqlx::query_scalar::<_, PaddedNumber<1, 3>>("SELECT number FROM numbers")
+        .fetch_one(&mut connection)
+        .await
+        .unwrap_err();
+
+    let sqlx::Error::ColumnDecode { source, .. } = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(ParsePaddedNumberError::TooLong(3, 4).to_string(), source.to_string());
+}
@@ -13,3 +13,22 @@ pub(crate) const fn number_len(number: u64) -> u8 {
 
     number_length
 }
+
+/// Writes the ASCII digits of a padded number into the end of the provided
+/// buffer, filling any remaining bytes before it with leading zeros.
+///
+/// # Panics
+/// - If the buffer is shorter than the number length
+#[cfg(feature = "unstable-nightly")]
+pub(crate) const fn write_ascii_digits(number: u64, buffer: &mut [u8]) {
+    let mut remaining_number = number;
+    let mut index = buffer.len();
+
+    while index > 0 {
+        index -= 1;
+        buffer[index] = b'0' + (remaining_number % 10) as u8;
+        remaining_number /= 10;
+    }
+
+    assert!(remaining_number == 0, "buffer too short for number");
+}
@@ -0,0 +1,179 @@
+use std::{ffi::OsStr, marker::PhantomData, ops::RangeInclusive};
+
+use ::clap::{
+    Arg, Command, Error,
+    builder::{StringValueParser, TypedValueParser, ValueParserFactory},
+};
+
+use crate::*;
+
+/// clap value parser for a [`PaddedNumber`]
+///
+/// Used by `clap::value_parser!(PaddedNumber<A, B>)` and thereby also by the
+/// clap derive macros. Errors are reported with the
+/// [`ParsePaddedNumberError`] message.
+///
+/// [`Self::augment_arg`] also adds the length bounds to the help text of the
+/// argument:
+///
+/// ```rust
+/// # use clap::{Arg, Command};
+/// # use padded_number::*;
+/// let mut command = Command::new("tool").arg(PaddedNumberValueParser::<2, 3>::augment_arg(
+///     Arg::new("id").long("id").help("Record ID"),
+/// ));
+///
+/// assert!(
+///     command
+///         .render_help()
+///         .to_string()
+///         .contains("Record ID (2-3 digits)")
+/// );
+///
+/// let matches = command
+///     .clone()
+///     .try_get_matches_from(["tool", "--id", "007"])
+///     .unwrap();
+/// assert_eq!(
+///     &bound_padded_number!(2, 3, "007"),
+///     matches.get_one::<PaddedNumber<2, 3>>("id").unwrap()
+/// );
+///
+/// assert!(
+///     command
+///         .try_get_matches_from(["tool", "--id", "0007"])
+///         .is_err()
+/// );
+/// ```
+///
+/// Fields using the derive macros may do the same by including
+/// [`Self::length_description`] in their help text:
+///
+/// ```text
+/// #[arg(long, help = format!("Record ID ({})", PaddedNumberValueParser::<2, 3>::length_description()))]
+/// id: PaddedNumber<2, 3>,
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PaddedNumberValueParser<const A: u8 = 1, const B: u8 = { u8::MAX }>(PhantomData<PaddedNumber<A, B>>);
+
+impl<const A: u8, const B: u8> PaddedNumberValueParser<A, B> {
+    /// Create a new [`PaddedNumberValueParser`]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Use this as the value parser of the argument, and append the
+    /// [`Self::length_description`] to its help text
+    ///
+    /// clap value parsers have no way of contributing to the help text of
+    /// their argument by themselves. The help text should therefore be set
+    /// before calling this, as setting it afterwards replaces the description.
+    pub fn augment_arg(arg: Arg) -> Arg {
+        let help = match arg.get_help() {
+            Some(help) => format!("{help} ({})", Self::length_description()),
+            None => Self::length_description(),
+        };
+
+        arg.value_parser(Self::new()).help(help)
+    }
+
+    /// Describe the allowed length for use in help texts, e.g. `"2-3 digits"`
+    pub fn length_description() -> String {
+        match (A, B) {
+            (1, 1) => "1 digit".to_string(),
+            _ if A == B => format!("{A} digits"),
+            (_, u8::MAX) => format!("{A} or more digits"),
+            _ => format!("{A}-{B} digits"),
+        }
+    }
+}
+
+impl<const A: u8, const B: u8> TypedValueParser for PaddedNumberValueParser<A, B> {
+    type Value = PaddedNumber<A, B>;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+        StringValueParser::new()
+            .try_map(|str| str.parse::<PaddedNumber<A, B>>())
+            .parse_ref(cmd, arg, value)
+    }
+}
+
+impl<const A: u8, const B: u8> ValueParserFactory for PaddedNumber<A, B> {
+    type Parser = PaddedNumberValueParser<A, B>;
+
+    fn value_parser() -> Self::Parser {
+        PaddedNumberValueParser::new()
+    }
+}
+
+/// clap value parser for an inclusive range of [`PaddedNumber`]s
+///
+/// Accepts `START..END`, and `START..=END` for those who prefer being
+/// explicit. Both ends are included, just as with the `{START..END}` brace
+/// expansion found in shells. Errors are reported with the
+/// [`ParsePaddedRangeError`] message.
+///
+/// ```rust
+/// # use clap::{Arg, Command};
+/// # use padded_number::*;
+/// let command = Command::new("tool").arg(
+///     Arg::new("range")
+///         .long("range")
+///         .value_parser(PaddedRangeValueParser::<4, 4>::new()),
+/// );
+///
+/// let matches = command
+///     .clone()
+///     .try_get_matches_from(["tool", "--range", "0001..0100"])
+///     .unwrap();
+/// let range = matches
+///     .get_one::<std::ops::RangeInclusive<PaddedNumber<4, 4>>>("range")
+///     .unwrap();
+/// assert_eq!(&bound_padded_number!(4, 4, "0001"), range.start());
+/// assert_eq!(&bound_padded_number!(4, 4, "0100"), range.end());
+///
+/// assert!(
+///     command
+///         .try_get_matches_from(["tool", "--range", "0100..0001"])
+///         .is_err()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PaddedRangeValueParser<const A: u8 = 1, const B: u8 = { u8::MAX }>(PhantomData<PaddedNumber<A, B>>);
+
+impl<const A: u8, const B: u8> PaddedRangeValueParser<A, B> {
+    /// Create a new [`PaddedRangeValueParser`]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<const A: u8, const B: u8> TypedValueParser for PaddedRangeValueParser<A, B> {
+    type Value = RangeInclusive<PaddedNumber<A, B>>;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+        StringValueParser::new()
+            .try_map(|str| parse_range::<A, B>(&str))
+            .parse_ref(cmd, arg, value)
+    }
+}
+
+fn parse_range<const A: u8, const B: u8>(
+    str: &str,
+) -> Result<RangeInclusive<PaddedNumber<A, B>>, ParsePaddedRangeError> {
+    let (start_str, end_str) = str.split_once("..").ok_or(ParsePaddedRangeError::MissingSeparator)?;
+    let end_str = end_str.strip_prefix('=').unwrap_or(end_str);
+
+    let start = start_str
+        .parse::<PaddedNumber<A, B>>()
+        .map_err(ParsePaddedRangeError::InvalidStart)?;
+    let end = end_str
+        .parse::<PaddedNumber<A, B>>()
+        .map_err(ParsePaddedRangeError::InvalidEnd)?;
+
+    if start > end {
+        return Err(ParsePaddedRangeError::StartAfterEnd);
+    }
+
+    Ok(start..=end)
+}
@@ -0,0 +1,110 @@
+//! NOTE: not under feature flagged value_parser module to ensure tests are
+//! run with `cargo test`
+
+use clap::{Arg, Command, error::ErrorKind, value_parser};
+
+use crate::{tests::mock_from_str, *};
+
+fn mock_command() -> Command {
+    Command::new("tool")
+        .arg(
+            Arg::new("id")
+                .long("id")
+                .value_parser(value_parser!(PaddedNumber<2, 3>)),
+        )
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .value_parser(PaddedRangeValueParser::<4, 4>::new()),
+        )
+}
+
+#[test]
+fn parses_padded_number() {
+    let matches = mock_command().try_get_matches_from(["tool", "--id", "007"]).unwrap();
+    assert_eq!(
+        &mock_from_str::<2, 3>("007"),
+        matches.get_one::<PaddedNumber<2, 3>>("id").unwrap()
+    );
+}
+
+#[test]
+fn padded_number_error() {
+    let err = mock_command()
+        .try_get_matches_from(["tool", "--id", "0007"])
+        .unwrap_err();
+
+    assert_eq!(ErrorKind::ValueValidation, err.kind());
+    assert!(
+        err.to_string()
+            .contains(&ParsePaddedNumberError::TooLong(3, 4).to_string())
+    );
+}
+
+#[test]
+fn length_descriptions() {
+    assert_eq!("2-3 digits", PaddedNumberValueParser::<2, 3>::length_description());
+    assert_eq!("0-1 digits", PaddedNumberValueParser::<0, 1>::length_description());
+    assert_eq!("4 digits", PaddedNumberValueParser::<4, 4>::length_description());
+    assert_eq!("1 digit", PaddedNumberValueParser::<1, 1>::length_description());
+    assert_eq!("1 or more digits", PaddedNumberValueParser::<1>::length_description());
+}
+
+#[test]
+fn augmented_help() {
+    let mut command = Command::new("tool")
+        .arg(PaddedNumberValueParser::<2, 3>::augment_arg(
+            Arg::new("id").long("id").help("Record ID"),
+        ))
+        .arg(PaddedNumberValueParser::<4, 4>::augment_arg(
+            Arg::new("code").long("code"),
+        ));
+
+    let help = command.render_help().to_string();
+    assert!(help.contains("Record ID (2-3 digits)"), "{help}");
+    assert!(help.contains("4 digits"), "{help}");
+
+    let matches = command.try_get_matches_from(["tool", "--id", "007"]).unwrap();
+    assert_eq!(
+        &mock_from_str::<2, 3>("007"),
+        matches.get_one::<PaddedNumber<2, 3>>("id").unwrap()
+    );
+}
+
+#[test]
+fn parses_range() {
+    for range_str in ["0001..0100", "0001..=0100"] {
+        let matches = mock_command()
+            .try_get_matches_from(["tool", "--range", range_str])
+            .unwrap();
+        let range = matches
+            .get_one::<std::ops::RangeInclusive<PaddedNumber<4, 4>>>("range")
+            .unwrap();
+
+        assert_eq!(&mock_from_str::<4, 4>("0001"), range.start());
+        assert_eq!(&mock_from_str::<4, 4>("0100"), range.end());
+    }
+}
+
+#[test]
+fn range_errors() {
+    assert_range_error("00010100", ParsePaddedRangeError::MissingSeparator);
+    assert_range_error(
+        "001..0100",
+        ParsePaddedRangeError::InvalidStart(ParsePaddedNumberError::TooShort(4, 3)),
+    );
+    assert_range_error(
+        "0001..00100",
+        ParsePaddedRangeError::InvalidEnd(ParsePaddedNumberError::TooLong(4, 5)),
+    );
+    assert_range_error("0100..0001", ParsePaddedRangeError::StartAfterEnd);
+
+    fn assert_range_error(range_str: &str, expected_err: ParsePaddedRangeError) {
+        let err = mock_command()
+            .try_get_matches_from(["tool", "--range", range_str])
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err.to_string().contains(&expected_err.to_string()), "{err}");
+    }
+}
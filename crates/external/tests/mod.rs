@@ -6,12 +6,230 @@ use padded_number::{PaddedNumber, bound_padded_number, padded_number};
 fn bound_const() {
     const BOUNDED_FROM_MACRO: PaddedNumber<1, 10> = bound_padded_number!(1, 10, "001");
 
-    assert_eq!(PaddedNumber::try_new("001").unwrap(), BOUNDED_FROM_MACRO);
+    assert_eq!(PaddedNumber::<1, 10>::try_new("001").unwrap(), BOUNDED_FROM_MACRO);
 }
 
 #[test]
 fn unbound_const() {
     const FROM_MACRO: PaddedNumber = padded_number!("001");
 
-    assert_eq!(PaddedNumber::try_new("001").unwrap(), FROM_MACRO);
+    assert_eq!(<PaddedNumber>::try_new("001").unwrap(), FROM_MACRO);
+}
+
+mod derive {
+    use std::str::FromStr;
+
+    use padded_number::{PaddedNumber, ParsePaddedNumberError, derive::PaddedNumber};
+
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, PaddedNumber)]
+    #[padded_number(serde)]
+    struct ZipCode(PaddedNumber<5, 5>);
+
+    #[derive(Clone, Copy, PartialEq, Eq, PaddedNumber)]
+    pub struct Code {
+        inner: PaddedNumber<1>,
+    }
+
+    mod nested {
+        #[derive(Clone, Copy, PartialEq, Eq, padded_number::derive::PaddedNumber)]
+        pub struct BranchNumber(padded_number::PaddedNumber<3, 4>);
+
+        #[allow(clippy::single_component_path_imports)]
+        pub(crate) use branch_number;
+    }
+
+    use nested::{BranchNumber, branch_number};
+
+    #[test]
+    fn const_constructors() {
+        const ZIP_CODE: ZipCode = zip_code!("01234");
+        const TRY_NEW: Result<ZipCode, ParsePaddedNumberError> = ZipCode::try_new("01234");
+
+        assert_eq!(Ok(ZIP_CODE), TRY_NEW);
+        assert_eq!(Ok(ZIP_CODE), ZipCode::try_from("01234"));
+        assert_eq!(Err(ParsePaddedNumberError::TooShort(5, 4)), ZipCode::from_str("1234"));
+
+        assert_eq!("0123", code!("0123").to_string());
+        assert_eq!("012", branch_number!("012").to_string());
+    }
+
+    #[test]
+    fn conversions() {
+        let zip_code = zip_code!("01234");
+        let padded_number: PaddedNumber<5, 5> = zip_code.into();
+
+        assert_eq!(zip_code, ZipCode::from(padded_number));
+        assert_eq!(padded_number, zip_code.into_padded_number());
+        assert_eq!(5, zip_code.len());
+        assert!(zip_code!("00001") < zip_code!("00010"));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!("01234", zip_code!("01234").to_string());
+        assert_eq!("ZipCode(01234)", format!("{:?}", zip_code!("01234")));
+        assert_eq!("Code(007)", format!("{:?}", code!("007")));
+    }
+
+    #[test]
+    fn serde() {
+        let zip_code = zip_code!("01234");
+        let json = serde_json::to_string(&zip_code).unwrap();
+
+        assert_eq!("\"01234\"", json);
+        assert_eq!(zip_code, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<ZipCode>("\"1234\"").is_err());
+    }
+}
+
+#[test]
+fn inferred_bounds() {
+    const ZIP_CODE: PaddedNumber<5, 5> = padded_number!("01234");
+    assert_eq!(PaddedNumber::<5, 5>::try_new("01234").unwrap(), ZIP_CODE);
+
+    fn len(padded_number: PaddedNumber<2, 3>) -> u8 {
+        padded_number.len()
+    }
+
+    assert_eq!(3, len(padded_number!("012")));
+
+    let numbers: [PaddedNumber<0, 2>; 3] = [padded_number!(""), padded_number!("0"), padded_number!("00")];
+    assert_eq!(["", "0", "00"], numbers.map(|number| number.to_string()));
+}
+
+mod const_expressions {
+    use padded_number::{PaddedNumber, bound_padded_number, padded_number};
+
+    const ZIP_LEN: u8 = 5;
+
+    #[derive(Clone, Copy, PartialEq, Eq, padded_number::derive::PaddedNumber)]
+    struct ZipCode(PaddedNumber<ZIP_LEN, ZIP_LEN>);
+
+    #[derive(Clone, Copy, PartialEq, Eq, padded_number::derive::PaddedNumber)]
+    struct Extension(PaddedNumber<{ ZIP_LEN - 3 }, { ZIP_LEN - 1 }>);
+
+    #[test]
+    fn const_bounds() {
+        const ZIP_CODE: PaddedNumber<ZIP_LEN, ZIP_LEN> = bound_padded_number!(ZIP_LEN, ZIP_LEN, "01234");
+        assert_eq!(PaddedNumber::<5, 5>::try_new("01234").unwrap(), ZIP_CODE);
+
+        let number = bound_padded_number!(ZIP_LEN - 3, ZIP_LEN + 1, "012");
+        assert_eq!(PaddedNumber::<2, 6>::try_new("012").unwrap(), number);
+    }
+
+    #[test]
+    fn macro_inputs() {
+        const CONCAT: PaddedNumber<3, 3> = bound_padded_number!(3, 3, concat!("0", "12"));
+        assert_eq!("012", CONCAT.to_string());
+
+        let major: PaddedNumber<1, 2> = padded_number!(env!("CARGO_PKG_VERSION_MAJOR"));
+        assert_eq!(env!("CARGO_PKG_VERSION_MAJOR"), major.to_string());
+    }
+
+    #[test]
+    fn derive_const_bounds() {
+        assert_eq!("01234", zip_code!("01234").to_string());
+        assert_eq!("012", extension!("012").to_string());
+        assert_eq!("0123", extension!(concat!("01", "23")).to_string());
+    }
+}
+
+#[test]
+fn integer_literals() {
+    const PADDED: PaddedNumber<4, 4> = padded_number!(42, width = 4);
+    assert_eq!("0042", PADDED.to_string());
+
+    let unpadded: PaddedNumber = padded_number!(42);
+    assert_eq!("42", unpadded.to_string());
+
+    let full_width: PaddedNumber<2, 2> = padded_number!(42, width = 2);
+    assert_eq!("42", full_width.to_string());
+}
+
+mod arrays {
+    use padded_number::{PaddedNumber, padded_numbers, padded_range};
+
+    #[test]
+    fn padded_numbers() {
+        const NUMBERS: [PaddedNumber<3, 3>; 3] = padded_numbers!["001", "002", "010"];
+        assert_eq!(["001", "002", "010"], NUMBERS.map(|number| number.to_string()));
+
+        let mixed: [PaddedNumber<1, 3>; 3] = padded_numbers!["01", 7, concat!("0", "0", "1")];
+        assert_eq!(["01", "7", "001"], mixed.map(|number| number.to_string()));
+
+        let empty: [PaddedNumber; 0] = padded_numbers![];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn padded_range() {
+        const RANGE: [PaddedNumber<4, 4>; 100] = padded_range!("0001", "0100");
+        assert_eq!("0001", RANGE[0].to_string());
+        assert_eq!("0100", RANGE[99].to_string());
+        assert!(RANGE.is_sorted());
+
+        let single: [PaddedNumber<2, 2>; 1] = padded_range!("05", "05");
+        assert_eq!("05", single[0].to_string());
+    }
+
+    #[test]
+    fn padded_range_across_lengths() {
+        let range: [PaddedNumber<0, 2>; 13] = padded_range!("", "01");
+        let strings = range.map(|number| number.to_string());
+
+        assert_eq!(["", "0", "1"], strings[..3]);
+        assert_eq!(["9", "00", "01"], strings[10..]);
+        assert!(range.is_sorted());
+    }
+}
+
+mod include {
+    use padded_number::{PaddedNumber, PaddedNumberPhfSet, include_padded_number_set, include_padded_numbers};
+
+    const BRANCH_NUMBERS: &[PaddedNumber<3, 4>] = include_padded_numbers!("tests/fixtures/branch_numbers.txt", 3, 4);
+
+    static TARIFF_CODES: PaddedNumberPhfSet<4, 4> = include_padded_number_set!("tests/fixtures/tariff_codes.txt", 4, 4);
+
+    #[test]
+    fn sorted_numbers() {
+        let branch_numbers = BRANCH_NUMBERS.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(["012", "100", "999", "0001", "0420"], branch_numbers.as_slice());
+    }
+
+    #[test]
+    fn const_bounds() {
+        const LEN: u8 = 4;
+        const NUMBERS: &[PaddedNumber<{ LEN - 1 }, LEN>] =
+            include_padded_numbers!("tests/fixtures/branch_numbers.txt", LEN - 1, LEN);
+
+        assert_eq!(BRANCH_NUMBERS.len(), NUMBERS.len());
+    }
+
+    #[test]
+    fn set_membership() {
+        assert_eq!(24, TARIFF_CODES.len());
+        assert!(TARIFF_CODES.as_slice().is_sorted());
+
+        for number in 0..=200 {
+            let tariff_code = PaddedNumber::<4, 4>::try_new(&format!("{number:04}")).unwrap();
+            let expected = number % 7 == 0 && number < 7 * 24;
+            assert_eq!(expected, TARIFF_CODES.contains(tariff_code), "{tariff_code}");
+        }
+    }
+
+    #[test]
+    fn const_set_membership() {
+        const BRANCH_NUMBER_SET: PaddedNumberPhfSet<3, 4> =
+            include_padded_number_set!("tests/fixtures/branch_numbers.txt", 3, 4);
+        const { assert!(BRANCH_NUMBER_SET.contains(padded_number::bound_padded_number!(3, 4, "0420"))) };
+        assert!(!BRANCH_NUMBER_SET.contains(padded_number::bound_padded_number!(3, 4, "420")));
+    }
+
+    #[test]
+    fn empty_set() {
+        const EMPTY: PaddedNumberPhfSet<3, 4> = include_padded_number_set!("tests/fixtures/empty.txt", 3, 4);
+
+        assert!(EMPTY.is_empty());
+        assert!(!EMPTY.contains(padded_number::bound_padded_number!(3, 4, "012")));
+    }
 }
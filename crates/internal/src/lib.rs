@@ -11,6 +11,63 @@ pub enum ParsePaddedNumberError {
     InvalidNumber(#[source] std::num::ParseIntError),
 }
 
+/// Error originating from decoding a `PaddedNumber` from its parts
+#[derive(Debug, PartialEq, displaydoc::Display, thiserror::Error)]
+pub enum DecodePaddedNumberError {
+    /// "too few digits encoded, expected at least '{0}', received '{1}'"
+    TooShort(u8, u8),
+    /// "too many digits encoded, expected at most '{0}', received '{1}'"
+    TooLong(u8, u8),
+    /// "encoded number '{1}' does not fit within the encoded length '{0}'"
+    NumberTooLong(u8, u64),
+}
+
+/// Error originating from parsing a range of `PaddedNumber`s
+#[derive(Debug, PartialEq, displaydoc::Display, thiserror::Error)]
+pub enum ParsePaddedRangeError {
+    /// "missing '..' separator between range start and end"
+    MissingSeparator,
+    /// "invalid range start, {0}"
+    InvalidStart(#[source] ParsePaddedNumberError),
+    /// "invalid range end, {0}"
+    InvalidEnd(#[source] ParsePaddedNumberError),
+    /// "range start is greater than range end"
+    StartAfterEnd,
+}
+
+/// Error originating from parsing `PaddedSegments`
+#[derive(Debug, PartialEq, displaydoc::Display, thiserror::Error)]
+pub enum ParsePaddedSegmentsError {
+    /// "expected '{0}' segments separated by '{1}', received '{2}'"
+    SegmentCount(usize, char, usize),
+    /// "invalid segment at index '{0}', {1}"
+    InvalidSegment(usize, #[source] ParsePaddedNumberError),
+}
+
+/// Error originating from parsing a `RangedPaddedNumber`
+#[derive(Debug, PartialEq, displaydoc::Display, thiserror::Error)]
+pub enum ParseRangedPaddedNumberError {
+    /// "invalid padded number, {0}"
+    InvalidPaddedNumber(#[source] ParsePaddedNumberError),
+    /// "value '{2}' is outside of the range '{0}' to '{1}'"
+    OutOfRange(u64, u64, u64),
+}
+
+/// Error originating from a `PaddedSequence`
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum SequenceError {
+    /// "failed to access the sequence state, {0}"
+    Io(#[from] std::io::Error),
+    /// "sequence state '{0}' is invalid, {1}"
+    InvalidState(String, #[source] ParsePaddedNumberError),
+    /// "sequence is exhausted"
+    Exhausted,
+}
+
+mod phf;
+#[doc(hidden)]
+pub use phf::{PhfHashes, phf_hashes, phf_index};
+
 #[doc(hidden)]
 pub const fn parse(min: u8, max: u8, str: &str) -> Result<(u8, u64), ParsePaddedNumberError> {
     {
@@ -0,0 +1,34 @@
+//! Perfect hashing shared by `include_padded_number_set!` and the set it
+//! expands to, following the hash and displace scheme of the `phf` crate.
+
+/// Hashes of a padded number, selecting its bucket and displaced index
+#[doc(hidden)]
+pub struct PhfHashes {
+    pub bucket: u32,
+    pub f1: u32,
+    pub f2: u32,
+}
+
+#[doc(hidden)]
+pub const fn phf_hashes(seed: u64, leading_zeros: u8, number: u64) -> PhfHashes {
+    let hash = mix(mix(seed ^ number) ^ leading_zeros as u64);
+
+    PhfHashes { bucket: (hash >> 32) as u32, f1: hash as u32, f2: mix(hash) as u32 }
+}
+
+#[doc(hidden)]
+pub const fn phf_index(hashes: &PhfHashes, displacement: (u32, u32), len: usize) -> usize {
+    let (d1, d2) = displacement;
+    let index = d2.wrapping_add(hashes.f1.wrapping_mul(d1)).wrapping_add(hashes.f2);
+
+    index as usize % len
+}
+
+/// splitmix64 finalizer
+const fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    Expr, LitStr,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
+};
+
+/// Upper limit on the number of elements `padded_range!` expands to
+const MAX_RANGE_COUNT: u128 = 1 << 16;
+
+pub(crate) fn padded_numbers_impl(elements: Punctuated<Expr, Comma>) -> syn::Result<TokenStream2> {
+    let padded_numbers = elements
+        .iter()
+        .map(|element| {
+            let input = crate::number_input(element, None)?;
+
+            Ok(quote_spanned! { element.span()=>
+                padded_number::PaddedNumber::new_or_panic(#input)
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        const { [#(#padded_numbers),*] }
+    })
+}
+
+pub(crate) struct RangeArgs {
+    start: LitStr,
+    end: LitStr,
+}
+
+impl Parse for RangeArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let start = input.parse()?;
+        let _comma = input.parse::<Comma>()?;
+        let end = input.parse()?;
+
+        Ok(RangeArgs { start, end })
+    }
+}
+
+pub(crate) fn padded_range_impl(args: RangeArgs) -> syn::Result<TokenStream2> {
+    let RangeArgs { start, end } = args;
+
+    let (start_length, start_number) = parts(&start)?;
+    let (end_length, end_number) = parts(&end)?;
+
+    if (end_length, end_number) < (start_length, start_number) {
+        return Err(syn::Error::new(end.span(), "range end is less than its start"));
+    }
+
+    let count = (start_length..=end_length)
+        .map(|length| {
+            let from = if length == start_length { start_number } else { 0 };
+            let to = if length == end_length {
+                end_number
+            } else {
+                max_number(length)
+            };
+            (to - from).saturating_add(1)
+        })
+        .fold(0_u128, u128::saturating_add);
+
+    if count > MAX_RANGE_COUNT {
+        return Err(syn::Error::new(
+            start.span(),
+            format!("range of {count} padded numbers is too large, at most {MAX_RANGE_COUNT} are supported"),
+        ));
+    }
+
+    let mut padded_numbers = Vec::with_capacity(count as usize);
+
+    for length in start_length..=end_length {
+        let from = if length == start_length { start_number } else { 0 };
+        let to = if length == end_length {
+            end_number
+        } else {
+            max_number(length)
+        };
+        // intermediate lengths are within the bounds whenever both ends are
+        let span = if length == start_length {
+            start.span()
+        } else {
+            end.span()
+        };
+
+        for number in from..=to {
+            let digits = match length {
+                0 => String::new(),
+                length => format!("{number:0>width$}", width = length as usize),
+            };
+            padded_numbers.push(quote_spanned! { span=>
+                padded_number::PaddedNumber::new_or_panic(#digits)
+            });
+        }
+    }
+
+    Ok(quote! {
+        const { [#(#padded_numbers),*] }
+    })
+}
+
+/// Length and remaining number of a padded number literal
+fn parts(lit_str: &LitStr) -> syn::Result<(u8, u128)> {
+    let digits = lit_str.value();
+
+    let (_, number) = padded_number_internal::parse(0, u8::MAX, &digits)
+        .map_err(|error| syn::Error::new(lit_str.span(), error.to_string()))?;
+
+    Ok((digits.len() as u8, number as u128))
+}
+
+/// Largest number of the given length, saturating at `u128::MAX`
+fn max_number(length: u8) -> u128 {
+    10_u128.checked_pow(length as u32).map_or(u128::MAX, |power| power - 1)
+}
@@ -0,0 +1,222 @@
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, GenericArgument, Member, PathArguments, Type, spanned::Spanned};
+
+pub(crate) fn derive_padded_number_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput { attrs, vis, ident, generics, data } = input;
+
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new(generics.span(), "generic newtypes are not supported"));
+    }
+
+    let Data::Struct(data) = data else {
+        return Err(syn::Error::new(ident.span(), "expected a newtype struct"));
+    };
+
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(syn::Error::new(
+            ident.span(),
+            "expected a struct with exactly one field",
+        ));
+    };
+
+    let member = match &field.ident {
+        Some(field_ident) => Member::Named(field_ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+
+    let (min, max) = bounds(&field.ty)?;
+    let inner = quote! { padded_number::PaddedNumber<#min, #max> };
+
+    let macro_ident = format_ident!("{}", snake_case(&ident.to_string()), span = ident.span());
+    let macro_doc = format!("Construct a [`{ident}`] at compile time, similar to `bound_padded_number!`");
+
+    let options = Options::parse(&attrs)?;
+    let serde_impls = match options.serde {
+        true => serde_impls(&ident, &inner),
+        false => TokenStream2::new(),
+    };
+
+    Ok(quote! {
+        impl #ident {
+            /// Create a new padded number newtype
+            #vis const fn try_new(str: &str) -> ::core::result::Result<Self, padded_number::ParsePaddedNumberError> {
+                match <#inner>::try_new(str) {
+                    ::core::result::Result::Ok(padded_number) => ::core::result::Result::Ok(Self::from_padded_number(padded_number)),
+                    ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+                }
+            }
+
+            /// Wrap a padded number
+            #vis const fn from_padded_number(padded_number: #inner) -> Self {
+                Self { #member: padded_number }
+            }
+
+            /// Unwrap the padded number
+            #vis const fn into_padded_number(self) -> #inner {
+                self.#member
+            }
+        }
+
+        impl ::core::str::FromStr for #ident {
+            type Err = padded_number::ParsePaddedNumberError;
+
+            fn from_str(str: &str) -> ::core::result::Result<Self, Self::Err> {
+                str.parse::<#inner>().map(Self::from_padded_number)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #ident {
+            type Error = padded_number::ParsePaddedNumberError;
+
+            fn try_from(str: &str) -> ::core::result::Result<Self, Self::Error> {
+                str.parse()
+            }
+        }
+
+        impl ::core::convert::From<#inner> for #ident {
+            fn from(padded_number: #inner) -> Self {
+                Self::from_padded_number(padded_number)
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #inner {
+            fn from(newtype: #ident) -> Self {
+                newtype.into_padded_number()
+            }
+        }
+
+        impl ::core::ops::Deref for #ident {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.#member, f)
+            }
+        }
+
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(stringify!(#ident)).field(&self.#member).finish()
+            }
+        }
+
+        #serde_impls
+
+        #[doc = #macro_doc]
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            ($number:expr) => {
+                #ident::from_padded_number(padded_number::bound_padded_number!(#min, #max, $number))
+            };
+        }
+    })
+}
+
+/// Options set through `#[padded_number(...)]` helper attributes
+#[derive(Default)]
+struct Options {
+    /// Implement `Serialize` and `Deserialize`, requires the `serde` feature
+    /// of `padded-number`
+    serde: bool,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("padded_number")) {
+            attr.parse_nested_meta(|meta| match meta.path.is_ident("serde") {
+                true => {
+                    options.serde = true;
+                    Ok(())
+                }
+                false => Err(meta.error("unsupported option, expected `serde`")),
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+fn serde_impls(ident: &syn::Ident, inner: &TokenStream2) -> TokenStream2 {
+    quote! {
+        impl padded_number::__private::serde::Serialize for #ident {
+            fn serialize<S: padded_number::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                padded_number::__private::serde::Serialize::serialize(&**self, serializer)
+            }
+        }
+
+        impl<'de> padded_number::__private::serde::Deserialize<'de> for #ident {
+            fn deserialize<D: padded_number::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <#inner as padded_number::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self::from_padded_number)
+            }
+        }
+    }
+}
+
+/// Length bounds of a `PaddedNumber<MIN, MAX>` type, falling back to the
+/// type's defaults for omitted ones
+fn bounds(ty: &Type) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let error = || syn::Error::new(ty.span(), "expected a `PaddedNumber<MIN, MAX>` field");
+
+    let Type::Path(type_path) = ty else {
+        return Err(error());
+    };
+
+    let segment = type_path.path.segments.last().ok_or_else(error)?;
+
+    if segment.ident != "PaddedNumber" {
+        return Err(error());
+    }
+
+    let mut bounds = [1, u8::MAX].map(|bound| Literal::u8_unsuffixed(bound).into_token_stream());
+
+    if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+        if arguments.args.len() > bounds.len() {
+            return Err(error());
+        }
+
+        for (bound, argument) in bounds.iter_mut().zip(&arguments.args) {
+            // const paths are parsed as types
+            let (GenericArgument::Const(_) | GenericArgument::Type(_)) = argument else {
+                return Err(error());
+            };
+
+            *bound = argument.into_token_stream();
+        }
+    }
+
+    let [min, max] = bounds;
+
+    Ok((min, max))
+}
+
+fn snake_case(str: &str) -> String {
+    let mut snake_case = String::with_capacity(str.len());
+
+    for (index, char) in str.char_indices() {
+        if char.is_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(char.to_lowercase());
+        } else {
+            snake_case.push(char);
+        }
+    }
+
+    snake_case
+}
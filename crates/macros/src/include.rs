@@ -0,0 +1,234 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use padded_number_internal::{phf_hashes, phf_index};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Expr, LitStr,
+    parse::{Parse, ParseStream},
+    token::Comma,
+};
+
+/// Average number of entries per perfect hash bucket
+const PHF_LAMBDA: usize = 5;
+
+pub(crate) struct IncludeArgs {
+    path: LitStr,
+    min: Expr,
+    max: Expr,
+}
+
+impl Parse for IncludeArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let _comma = input.parse::<Comma>()?;
+        let min = input.parse()?;
+        let _comma = input.parse::<Comma>()?;
+        let max = input.parse()?;
+
+        Ok(IncludeArgs { path, min, max })
+    }
+}
+
+/// A validated padded number from an included file
+struct Entry {
+    line_number: usize,
+    digits: String,
+    leading_zeros: u8,
+    number: u64,
+}
+
+pub(crate) fn include_padded_numbers_impl(args: IncludeArgs) -> syn::Result<TokenStream2> {
+    let entries = read_entries(&args)?;
+
+    Ok(slice_tokens(&args, &entries))
+}
+
+pub(crate) fn include_padded_number_set_impl(args: IncludeArgs) -> syn::Result<TokenStream2> {
+    let entries = read_entries(&args)?;
+    let numbers = slice_tokens(&args, &entries);
+
+    let Phf { seed, displacements, slots } = generate_phf(&entries);
+    let displacements = displacements.iter().map(|(d1, d2)| quote! { (#d1, #d2) });
+
+    let IncludeArgs { min, max, .. } = args;
+
+    Ok(quote! {
+        const {
+            padded_number::PaddedNumberPhfSet::<{ #min }, { #max }>::from_raw_parts(
+                #seed,
+                &[#(#displacements),*],
+                &[#(#slots),*],
+                #numbers,
+            )
+        }
+    })
+}
+
+/// Reads, validates and sorts the padded numbers of the included file
+///
+/// Blank lines and lines starting with `#` are skipped, surrounding whitespace
+/// is trimmed. All invalid lines are reported at once, prefixed by their
+/// location.
+fn read_entries(args: &IncludeArgs) -> syn::Result<Vec<Entry>> {
+    let IncludeArgs { path, min, max } = args;
+
+    let absolute_path = absolute_path(path)?;
+    let content = std::fs::read_to_string(&absolute_path).map_err(|error| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read '{}', {error}", absolute_path.display()),
+        )
+    })?;
+
+    // bounds which are only known after const evaluation are checked then
+    let (min_bound, max_bound) = match (crate::literal_bound(min)?, crate::literal_bound(max)?) {
+        (Some(min_bound), Some(max_bound)) => (min_bound, max_bound),
+        _ => (0, u8::MAX),
+    };
+
+    let mut errors = Vec::new();
+    let mut lines = BTreeMap::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let digits = line.trim();
+
+        if digits.is_empty() || digits.starts_with('#') {
+            continue;
+        }
+
+        match padded_number_internal::parse(min_bound, max_bound, digits) {
+            Ok((leading_zeros, number)) => {
+                let entry = Entry { line_number, digits: digits.to_string(), leading_zeros, number };
+                // keyed by length and then number, following the `PaddedNumber` ordering
+                if let Some(first_entry) = lines.insert((digits.len(), number), entry) {
+                    errors.push(format!(
+                        "{}:{line_number}: duplicate of line {}",
+                        path.value(),
+                        first_entry.line_number
+                    ));
+                }
+            }
+            Err(error) => errors.push(format!("{}:{line_number}: {error}", path.value())),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(syn::Error::new(path.span(), errors.join("\n")));
+    }
+
+    Ok(lines.into_values().collect())
+}
+
+/// Included files are resolved relative to `CARGO_MANIFEST_DIR`, the
+/// directory of the compiled crate's `Cargo.toml`. Unlike with `include_str!`,
+/// the location of the invoking source file does not matter.
+fn absolute_path(path: &LitStr) -> syn::Result<PathBuf> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(path.span(), "`CARGO_MANIFEST_DIR` is not set"))?;
+
+    Ok(PathBuf::from(manifest_dir).join(path.value()))
+}
+
+fn slice_tokens(args: &IncludeArgs, entries: &[Entry]) -> TokenStream2 {
+    let IncludeArgs { path, min, max } = args;
+
+    // makes cargo rebuild the crate whenever the included file changes
+    let absolute_path = absolute_path(path)
+        .expect("path resolved when reading entries")
+        .display()
+        .to_string();
+
+    let numbers = entries.iter().map(|Entry { line_number, digits, .. }| {
+        let message = format!(
+            "{}:{line_number}: '{digits}' is not within the `PaddedNumber` length bounds",
+            path.value()
+        );
+
+        quote! {
+            match padded_number::PaddedNumber::<{ #min }, { #max }>::try_new(#digits) {
+                ::core::result::Result::Ok(padded_number) => padded_number,
+                ::core::result::Result::Err(_) => ::core::panic!(#message),
+            }
+        }
+    });
+
+    quote! {
+        {
+            const _: &[u8] = ::core::include_bytes!(#absolute_path);
+            const PADDED_NUMBERS: &[padded_number::PaddedNumber<{ #min }, { #max }>] = &[#(#numbers),*];
+            PADDED_NUMBERS
+        }
+    }
+}
+
+/// Perfect hash function mapping each entry to a distinct slot
+struct Phf {
+    seed: u64,
+    /// Displacement of each bucket
+    displacements: Vec<(u32, u32)>,
+    /// Index into the sorted entries for each slot
+    slots: Vec<u32>,
+}
+
+fn generate_phf(entries: &[Entry]) -> Phf {
+    let bucket_count = entries.len().div_ceil(PHF_LAMBDA).max(1);
+
+    (0..)
+        .find_map(|seed| try_generate_phf(seed, bucket_count, entries))
+        .expect("a seed to eventually be found")
+}
+
+fn try_generate_phf(seed: u64, bucket_count: usize, entries: &[Entry]) -> Option<Phf> {
+    let hashes = entries
+        .iter()
+        .map(|entry| phf_hashes(seed, entry.leading_zeros, entry.number))
+        .collect::<Vec<_>>();
+
+    let mut buckets = vec![Vec::new(); bucket_count];
+    for (entry_index, hash) in hashes.iter().enumerate() {
+        buckets[hash.bucket as usize % bucket_count].push(entry_index);
+    }
+
+    let mut bucket_order = (0..bucket_count).collect::<Vec<_>>();
+    bucket_order.sort_by_key(|bucket_index| std::cmp::Reverse(buckets[*bucket_index].len()));
+
+    let len = entries.len();
+    let mut displacements = vec![(0, 0); bucket_count];
+    let mut slots = vec![None; len];
+
+    'buckets: for bucket_index in bucket_order {
+        let bucket = &buckets[bucket_index];
+        if bucket.is_empty() {
+            continue;
+        }
+
+        for d1 in 0..len as u32 {
+            'displacements: for d2 in 0..len as u32 {
+                let mut bucket_slots = Vec::with_capacity(bucket.len());
+
+                for entry_index in bucket {
+                    let slot = phf_index(&hashes[*entry_index], (d1, d2), len);
+                    if slots[slot].is_some() || bucket_slots.contains(&slot) {
+                        continue 'displacements;
+                    }
+                    bucket_slots.push(slot);
+                }
+
+                for (slot, entry_index) in bucket_slots.into_iter().zip(bucket) {
+                    slots[slot] = Some(*entry_index as u32);
+                }
+                displacements[bucket_index] = (d1, d2);
+
+                continue 'buckets;
+            }
+        }
+
+        return None;
+    }
+
+    let slots = slots.into_iter().map(|slot| slot.expect("all slots filled")).collect();
+
+    Some(Phf { seed, displacements, slots })
+}
@@ -1,35 +1,94 @@
 //! # `padded-number-macros` - Macros for compile time `padded-number` constructs
 
+mod array;
+mod derive;
+mod include;
+
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
-    LitInt, LitStr,
+    DeriveInput, Expr, ExprLit, Ident, Lit, LitInt, LitStr, Token,
     parse::{Parse, ParseStream},
     parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
     token::Comma,
 };
 
 /// Construct a `PaddedNumber` at compile time
 ///
-/// May be seend as shorthand for writing `PaddedNumber::<1, {
-/// u8::MAX}>::try_new("001").unwrap()`, but compile time error reporting.
+/// Length bounds are inferred from the expected type, and may be seen as
+/// shorthand for writing `const { PaddedNumber::<A, B>::try_new("001").unwrap()
+/// }`, but with compile time error reporting.
 ///
-/// Errors if provided string is not within the provided length bounds, or it it
-/// constrains anything but ASCII digits.
+/// Errors if provided string contains anything but ASCII digits, or if it is
+/// not within the length bounds of the expected type. The latter is checked
+/// once the bounds are known, when the expansion is const evaluated.
 ///
 /// Works in const context:
 ///
 /// ```no_compile
-/// const PADDED_NUMBER: PaddedNumber = padded_number!("001");
+/// const PADDED_NUMBER: PaddedNumber<5, 5> = padded_number!("00123");
+/// ```
+///
+/// Where there's no expected type to infer the bounds from, they must be
+/// annotated, or provided by using `bound_padded_number!` instead:
+///
+/// ```no_compile
+/// let padded_number: PaddedNumber = padded_number!("001");
+/// ```
+///
+/// Integer literals may be used as input as well, optionally zero padded to a
+/// given width:
+///
+/// ```no_compile
+/// const PADDED_NUMBER: PaddedNumber<4, 4> = padded_number!(42, width = 4);
 /// ```
 #[proc_macro]
 pub fn padded_number(token_stream: TokenStream) -> TokenStream {
-    let number_literal = parse_macro_input!(token_stream as LitStr);
+    let NumberArgs { number, width } = parse_macro_input!(token_stream as NumberArgs);
 
-    let args = Args { min: 1, max: u8::MAX, number_literal };
+    padded_number_impl(&number, width.as_ref())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    padded_number_impl(args).into()
+/// Construct an array of `PaddedNumber`s at compile time
+///
+/// Each element is validated like with `padded_number!`, with errors pointing
+/// at the offending element. Length bounds are inferred from the expected
+/// type.
+///
+/// ```no_compile
+/// const PADDED_NUMBERS: [PaddedNumber<3, 3>; 3] = padded_numbers!["001", "002", "010"];
+/// ```
+#[proc_macro]
+pub fn padded_numbers(token_stream: TokenStream) -> TokenStream {
+    let elements = parse_macro_input!(token_stream with Punctuated::<Expr, Comma>::parse_terminated);
+
+    array::padded_numbers_impl(elements)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Construct an array of all `PaddedNumber`s within an inclusive range at
+/// compile time
+///
+/// Ranges follow the ordering of `PaddedNumber`, so shorter numbers precede
+/// longer ones, e.g. `"9" < "00"`. Length bounds are inferred from the
+/// expected type.
+///
+/// ```no_compile
+/// const PADDED_NUMBERS: [PaddedNumber<4, 4>; 100] = padded_range!("0001", "0100");
+/// ```
+#[proc_macro]
+pub fn padded_range(token_stream: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(token_stream as array::RangeArgs);
+
+    array::padded_range_impl(args)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Construct a bound `PaddedNumber` at compile time, similar to
@@ -43,46 +102,253 @@ pub fn padded_number(token_stream: TokenStream) -> TokenStream {
 /// ```no_compile
 /// const PADDED_NUMBER: PaddedNumber<1, 3> = bound_padded_number!(1, 3, "001");
 /// ```
+///
+/// Bounds may also be const expressions, and the input any expression
+/// evaluating to a `&'static str` in const context, such as `env!` or
+/// `concat!`. Input validation is then left to const evaluation, still
+/// failing to compile on invalid input:
+///
+/// ```no_compile
+/// const ZIP_LEN: u8 = 5;
+/// const ZIP_CODE: PaddedNumber<ZIP_LEN, ZIP_LEN> = bound_padded_number!(ZIP_LEN, ZIP_LEN, concat!("0", "1234"));
+/// ```
 #[proc_macro]
 pub fn bound_padded_number(token_stream: TokenStream) -> TokenStream {
     let args = parse_macro_input!(token_stream as Args);
-    padded_number_impl(args).into()
+
+    bound_padded_number_impl(args)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Include a sorted `&'static [PaddedNumber<MIN, MAX>]` from a file at
+/// compile time
+///
+/// The path is resolved relative to `CARGO_MANIFEST_DIR`, the directory
+/// containing the `Cargo.toml` of the crate being compiled. This differs from
+/// `include_str!`, which resolves paths relative to the invoking source file.
+///
+/// The file is expected to contain one padded number per line. Blank lines
+/// and lines starting with `#` are skipped, surrounding whitespace is trimmed.
+/// Invalid and duplicate lines fail the compilation, reported by their
+/// `file:line` location.
+///
+/// ```no_compile
+/// // reads `<crate root>/data/branch_numbers.txt`, wherever this is invoked
+/// const BRANCH_NUMBERS: &[PaddedNumber<3, 4>] = include_padded_numbers!("data/branch_numbers.txt", 3, 4);
+/// ```
+#[proc_macro]
+pub fn include_padded_numbers(token_stream: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(token_stream as include::IncludeArgs);
+
+    include::include_padded_numbers_impl(args)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Include a `PaddedNumberPhfSet<MIN, MAX>` from a file at compile time
+///
+/// Reads the file like `include_padded_numbers!`, relative to
+/// `CARGO_MANIFEST_DIR`, and builds a perfect hash set from its padded numbers
+/// for constant time membership checks.
+///
+/// ```no_compile
+/// static TARIFF_CODES: PaddedNumberPhfSet<6, 6> = include_padded_number_set!("tariff_codes.txt", 6, 6);
+/// ```
+#[proc_macro]
+pub fn include_padded_number_set(token_stream: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(token_stream as include::IncludeArgs);
+
+    include::include_padded_number_set_impl(args)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the boilerplate of a newtype over `PaddedNumber`
+///
+/// Expects a struct with a single `PaddedNumber<MIN, MAX>` field, for which
+/// the bounds may be omitted. Generates:
+///
+/// - `try_new`, `from_padded_number` and `into_padded_number` const fns.
+/// - `FromStr`, `TryFrom<&str>`, `Display`, `Debug` and `Deref` impls, along
+///   with `From` impls to and from the padded number.
+/// - `Serialize` and `Deserialize` impls when opted into with
+///   `#[padded_number(serde)]`, which requires the `serde` feature of
+///   `padded-number`.
+/// - A constructor macro named after the newtype in snake case, validating its
+///   input at compile time like `bound_padded_number!`.
+///
+/// ```no_compile
+/// #[derive(Clone, Copy, PartialEq, Eq, padded_number::derive::PaddedNumber)]
+/// #[padded_number(serde)]
+/// struct ZipCode(PaddedNumber<5, 5>);
+///
+/// const ZIP_CODE: ZipCode = zip_code!("01234");
+/// ```
+///
+/// The constructor macro is scoped like any other `macro_rules!` macro, and
+/// may be re-exported with `pub(crate) use zip_code;`. The newtype must then be
+/// in scope wherever the macro is used.
+#[proc_macro_derive(PaddedNumber, attributes(padded_number))]
+pub fn derive_padded_number(token_stream: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(token_stream as DeriveInput);
+
+    derive::derive_padded_number_impl(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 struct Args {
-    min: u8,
-    max: u8,
-    number_literal: LitStr,
+    min: Expr,
+    max: Expr,
+    number: Expr,
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let min = input.parse::<LitInt>()?.base10_parse()?;
+        let min = input.parse()?;
         let _comma = input.parse::<Comma>()?;
-        let max = input.parse::<LitInt>()?.base10_parse()?;
+        let max = input.parse()?;
         let _comma = input.parse::<Comma>()?;
-        let number_string = input.parse::<LitStr>()?;
+        let number = input.parse()?;
 
-        Ok(Args { min, max, number_literal: number_string })
+        Ok(Args { min, max, number })
     }
 }
 
-fn padded_number_impl(args: Args) -> TokenStream2 {
-    let Args { min, max, number_literal } = args;
-    let number_str = number_literal.value();
-
-    match padded_number_internal::parse(min, max, &number_str) {
-        Ok((leading_zeros, remaining_number)) => {
-            quote! {
-                // SAFETY: invariants verified by proc macro
-                unsafe {
-                    padded_number::PaddedNumber::<#min, #max>::new_unchecked(
-                        #leading_zeros,
-                        #remaining_number
-                    )
-                }
+struct NumberArgs {
+    number: Expr,
+    width: Option<LitInt>,
+}
+
+impl Parse for NumberArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let number = input.parse()?;
+
+        if input.is_empty() {
+            return Ok(NumberArgs { number, width: None });
+        }
+
+        let _comma = input.parse::<Comma>()?;
+        let ident = input.parse::<Ident>()?;
+        if ident != "width" {
+            return Err(syn::Error::new(ident.span(), "expected `width = <integer>`"));
+        }
+        let _eq = input.parse::<Token![=]>()?;
+        let width = input.parse()?;
+
+        Ok(NumberArgs { number, width: Some(width) })
+    }
+}
+
+fn padded_number_impl(number: &Expr, width: Option<&LitInt>) -> syn::Result<TokenStream2> {
+    let input = number_input(number, width)?;
+
+    Ok(quote_spanned! { number.span()=>
+        const { padded_number::PaddedNumber::new_or_panic(#input) }
+    })
+}
+
+fn bound_padded_number_impl(args: Args) -> syn::Result<TokenStream2> {
+    let Args { min, max, number } = args;
+
+    let (Some(min_bound), Some(max_bound), Some(number_literal)) =
+        (literal_bound(&min)?, literal_bound(&max)?, string_literal(&number))
+    else {
+        validate_digits(&number)?;
+
+        return Ok(quote! {
+            const { padded_number::PaddedNumber::<{ #min }, { #max }>::new_or_panic(#number) }
+        });
+    };
+
+    let (leading_zeros, remaining_number) =
+        padded_number_internal::parse(min_bound, max_bound, &number_literal.value())
+            .map_err(|error| syn::Error::new(number_literal.span(), error.to_string()))?;
+
+    Ok(quote! {
+        // SAFETY: invariants verified by proc macro
+        unsafe {
+            padded_number::PaddedNumber::<#min_bound, #max_bound>::new_unchecked(
+                #leading_zeros,
+                #remaining_number
+            )
+        }
+    })
+}
+
+/// Converts the macro input into a `&str` expression
+///
+/// Integer literals are zero padded to the optional width, other inputs are
+/// passed through as is after being validated by [`validate_digits`].
+fn number_input(number: &Expr, width: Option<&LitInt>) -> syn::Result<TokenStream2> {
+    let Expr::Lit(ExprLit { lit: Lit::Int(lit_int), .. }) = ungroup(number) else {
+        if let Some(width) = width {
+            return Err(syn::Error::new(
+                width.span(),
+                "width is only supported for integer literals",
+            ));
+        }
+
+        validate_digits(number)?;
+
+        return Ok(number.to_token_stream());
+    };
+
+    let digits = lit_int.base10_digits();
+
+    let padded_digits = match width {
+        Some(width) => {
+            let width = width.base10_parse::<u8>()? as usize;
+            if digits.len() > width {
+                return Err(syn::Error::new(
+                    lit_int.span(),
+                    format!("integer has more digits than the provided width of {width}"),
+                ));
             }
+            format!("{digits:0>width$}")
         }
-        Err(error) => syn::Error::new(number_literal.span(), error.to_string()).into_compile_error(),
+        None => digits.to_string(),
+    };
+
+    padded_number_internal::parse(0, u8::MAX, &padded_digits)
+        .map_err(|error| syn::Error::new(lit_int.span(), error.to_string()))?;
+
+    Ok(LitStr::new(&padded_digits, lit_int.span()).into_token_stream())
+}
+
+/// Checks that a string literal input only contains ASCII digits, and that
+/// it isn't longer than any length bounds would allow
+///
+/// Other inputs are left to const evaluation.
+fn validate_digits(number: &Expr) -> syn::Result<()> {
+    let Some(number_literal) = string_literal(number) else {
+        return Ok(());
+    };
+
+    padded_number_internal::parse(0, u8::MAX, &number_literal.value())
+        .map(|_| ())
+        .map_err(|error| syn::Error::new(number_literal.span(), error.to_string()))
+}
+
+fn literal_bound(bound: &Expr) -> syn::Result<Option<u8>> {
+    match ungroup(bound) {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit_int), .. }) => lit_int.base10_parse().map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn string_literal(number: &Expr) -> Option<&LitStr> {
+    match ungroup(number) {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => Some(lit_str),
+        _ => None,
+    }
+}
+
+/// Strips the invisible groups left by `macro_rules!` fragments
+fn ungroup(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Group(group) => ungroup(&group.expr),
+        expr => expr,
     }
 }